@@ -1,21 +1,36 @@
 #![allow(unused)]
 
-use strict_result::{Strict, StrictResult};
+use strict_result::{Strict, StrictResult, StrictOption, StrictOptionExt};
 
+#[cfg(not(feature = "stable"))]
 fn returns_result<E>() -> Result<(), E> {
 	returns_strict()?;
 	Ok(())
 }
 
+#[cfg(not(feature = "stable"))]
 fn returns_strict<E>() -> StrictResult<(), E> {
 	returns_result()?;
 	Ok(()).strict()
 }
 
+#[cfg(not(feature = "stable"))]
+fn returns_option() -> Option<()> {
+	returns_strict_option()?;
+	Some(())
+}
+
+#[cfg(not(feature = "stable"))]
+fn returns_strict_option() -> StrictOption<()> {
+	returns_option()?;
+	Some(()).strict()
+}
+
 fn passthrough<T>(f: impl FnOnce() -> T) -> T {
 	f()
 }
 
+#[cfg(not(feature = "stable"))]
 fn example() -> std::io::Result<()> {
 	passthrough(|| {
 		std::fs::create_dir("example")?;
@@ -44,6 +59,7 @@ fn foob() -> Result<(), AError> {
 	Ok(())
 }
 
+#[cfg(not(feature = "stable"))]
 fn foo<E>() -> StrictResult<(), E>
 where
 	E: From<AError> + From<BError>,
@@ -52,6 +68,7 @@ where
 	Ok(()).strict()
 }
 
+#[cfg(not(feature = "stable"))]
 fn bar() -> Result<(), ABError> {
 	foo()?;
 	Ok(())
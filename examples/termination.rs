@@ -0,0 +1,25 @@
+#![allow(unused)]
+
+use strict_result::{Strict, StrictResult};
+
+#[derive(Debug)]
+enum Error {
+	MissingArgument,
+	Parse(std::num::ParseIntError),
+}
+
+#[cfg(not(feature = "stable"))]
+fn main() -> StrictResult<(), Error> {
+	let arg = std::env::args().nth(1).ok_or(Error::MissingArgument).strict()?;
+	let n = arg.parse::<i32>().map_err(Error::Parse).strict()?;
+	println!("{}", n * 2);
+	Ok(()).strict()
+}
+
+#[cfg(feature = "stable")]
+fn main() -> StrictResult<(), Error> {
+	let arg = strict_result::strict_try!(std::env::args().nth(1).ok_or(Error::MissingArgument));
+	let n = strict_result::strict_try!(arg.parse::<i32>().map_err(Error::Parse));
+	println!("{}", n * 2);
+	Ok(()).strict()
+}
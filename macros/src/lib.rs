@@ -0,0 +1,246 @@
+#![warn(missing_docs)]
+
+/*!
+Procedural macros for [`strict_result`](https://docs.rs/strict_result).
+
+These are re-exported from `strict_result::macros` when its `macros` feature is enabled.
+*/
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::visit_mut::VisitMut;
+
+/// Makes every `?` in a function strict.
+///
+/// Each `expr?` in the function body is rewritten into `expr.strict()?`. Expressions that are
+/// already strict are left as they are, as are `Option`, `ControlFlow`, and `Poll` of a `Result`
+/// or `Option<Result>`. `?` on any other type is rejected.
+///
+/// ```
+/// use std::ops::ControlFlow;
+/// use std::task::Poll;
+/// use strict_result_macros::strict;
+///
+/// #[strict]
+/// fn first_even(v: &[i32]) -> ControlFlow<i32> {
+///     for &x in v {
+///         if x % 2 == 0 { ControlFlow::Break(x)? }
+///     }
+///     ControlFlow::Continue(())
+/// }
+/// assert_eq!(first_even(&[1, 2, 3]), ControlFlow::Break(2));
+///
+/// #[strict]
+/// fn poll_double(p: Poll<Result<i32, ()>>) -> Result<Poll<i32>, ()> {
+///     let x = p?;
+///     Ok(x.map(|v| v * 2))
+/// }
+/// assert_eq!(poll_double(Poll::Ready(Ok(2))), Ok(Poll::Ready(4)));
+/// assert_eq!(poll_double(Poll::Ready(Err(()))), Err(()));
+/// assert_eq!(poll_double(Poll::Pending), Ok(Poll::Pending));
+/// ```
+///
+/// `?` inside closures and async blocks refers to the closure rather than the function, so these
+/// are not rewritten, unless the closure itself is also annotated with `#[strict]`. Nested items
+/// and macro invocations are not rewritten either.
+///
+/// ```compile_fail
+/// fn passthrough<T>(f: impl FnOnce() -> T) -> T {
+///     f()
+/// }
+///
+/// fn lenient() -> std::io::Result<()> {
+///     passthrough(|| {
+///         std::fs::create_dir("example")?;
+///         Ok(()) // cannot infer type of the type parameter `E` declared on the enum `Result`
+///     })?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ```
+/// use strict_result_macros::strict;
+///
+/// # fn passthrough<T>(f: impl FnOnce() -> T) -> T {
+/// #     f()
+/// # }
+/// #[strict]
+/// fn strict() -> std::io::Result<()> {
+///     passthrough(|| {
+///         std::fs::create_dir("example")?;
+///         Ok(())
+///     })?;
+///     Ok(())
+/// }
+/// ```
+///
+/// A single `?` can still be allowed to convert the error with
+/// [`widening`](https://docs.rs/strict_result/latest/strict_result/struct.Widen.html):
+///
+/// ```
+/// use strict_result::Strict;
+/// use strict_result_macros::strict;
+///
+/// # #[derive(Debug, PartialEq)]
+/// # struct AError;
+/// # #[derive(Debug, PartialEq)]
+/// # struct ABError(AError);
+/// #
+/// # impl From<AError> for ABError {
+/// #     fn from(a: AError) -> ABError { ABError(a) }
+/// # }
+/// #
+/// #[strict]
+/// fn strict(r: Result<i32, AError>) -> Result<i32, ABError> {
+///     let x = r.strict().widening()?;
+///     Ok(x + 1)
+/// }
+/// assert_eq!(strict(Ok(1)), Ok(2));
+/// assert_eq!(strict(Err(AError)), Err(ABError(AError)));
+/// ```
+///
+/// Closures are only rewritten if they are annotated:
+///
+/// ```compile_fail
+/// use strict_result_macros::strict;
+///
+/// # fn passthrough<T>(f: impl FnOnce() -> T) -> T {
+/// #     f()
+/// # }
+/// #[strict]
+/// fn strict() -> std::io::Result<()> {
+///     let f = || {
+///         passthrough(|| {
+///             std::fs::create_dir("example")?;
+///             Ok(())
+///         })?;
+///         std::io::Result::Ok(())
+///     };
+///     f()
+/// }
+/// ```
+///
+/// ```
+/// use strict_result_macros::strict;
+///
+/// # fn passthrough<T>(f: impl FnOnce() -> T) -> T {
+/// #     f()
+/// # }
+/// #[strict]
+/// fn strict() -> std::io::Result<()> {
+///     let f = #[strict] || {
+///         passthrough(|| {
+///             std::fs::create_dir("example")?;
+///             Ok(())
+///         })?;
+///         std::io::Result::Ok(())
+///     };
+///     f()
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn strict(attr: TokenStream, item: TokenStream) -> TokenStream {
+	let attr = proc_macro2::TokenStream::from(attr);
+	if !attr.is_empty() {
+		return syn::Error::new_spanned(attr, "#[strict] takes no arguments")
+			.to_compile_error()
+			.into();
+	}
+	let mut item = syn::parse_macro_input!(item as syn::ItemFn);
+	Rewrite.visit_block_mut(&mut item.block);
+	quote!(#item).into()
+}
+
+/// Makes every `?` in a block strict.
+///
+/// This applies the same rewriting as [`#[strict]`](macro@strict) to the statements inside the
+/// macro, and evaluates to the value of the block. `?` outside of the macro is left as it is.
+///
+/// ```compile_fail
+/// # fn passthrough<T>(f: impl FnOnce() -> T) -> T {
+/// #     f()
+/// # }
+/// fn lenient() -> std::io::Result<()> {
+///     let x = passthrough(|| {
+///         std::fs::create_dir("example")?;
+///         Ok(1) // cannot infer type of the type parameter `E` declared on the enum `Result`
+///     })?;
+///     Ok(())
+/// }
+/// ```
+///
+/// ```
+/// use strict_result_macros::strict_block;
+///
+/// # fn passthrough<T>(f: impl FnOnce() -> T) -> T {
+/// #     f()
+/// # }
+/// fn strict() -> std::io::Result<i32> {
+///     let x = strict_block! {
+///         let x = passthrough(|| {
+///             std::fs::create_dir("example")?;
+///             Ok(1)
+///         })?;
+///         x + 1
+///     };
+///     Ok(x)
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use strict_result_macros::strict_block;
+///
+/// # fn passthrough<T>(f: impl FnOnce() -> T) -> T {
+/// #     f()
+/// # }
+/// fn strict() -> std::io::Result<i32> {
+///     let x = strict_block! {
+///         1
+///     };
+///     passthrough(|| {
+///         std::fs::create_dir("example")?;
+///         Ok(())
+///     })?;
+///     Ok(x)
+/// }
+/// ```
+#[proc_macro]
+pub fn strict_block(input: TokenStream) -> TokenStream {
+	let block = syn::parse_macro_input!(input with syn::Block::parse_within);
+	let mut block = syn::Block { brace_token: Default::default(), stmts: block };
+	Rewrite.visit_block_mut(&mut block);
+	quote!(#block).into()
+}
+
+struct Rewrite;
+
+impl Rewrite {
+	fn take_strict_attr(attrs: &mut Vec<syn::Attribute>) -> bool {
+		let len = attrs.len();
+		attrs.retain(|a| !a.path().is_ident("strict"));
+		attrs.len() != len
+	}
+}
+
+impl VisitMut for Rewrite {
+	fn visit_expr_mut(&mut self, expr: &mut syn::Expr) {
+		match expr {
+			syn::Expr::Closure(closure) => {
+				if Self::take_strict_attr(&mut closure.attrs) {
+					self.visit_expr_mut(&mut closure.body);
+				}
+			}
+			syn::Expr::Async(_) => {}
+			syn::Expr::Try(try_) => {
+				self.visit_expr_mut(&mut try_.expr);
+				let inner = &try_.expr;
+				*try_.expr = syn::parse_quote! {
+					::strict_result::__private::IntoStrict::into_strict(#inner)
+				};
+			}
+			_ => syn::visit_mut::visit_expr_mut(self, expr),
+		}
+	}
+
+	fn visit_item_mut(&mut self, _: &mut syn::Item) {}
+}
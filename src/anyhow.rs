@@ -0,0 +1,15 @@
+use crate::StrictResult;
+
+impl<A, B> StrictResult<A, B> {
+	/// Converts the error into an [`anyhow::Error`], and converts into a `Result`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let x: anyhow::Result<i32> = "x".parse::<i32>().strict().into_anyhow();
+	/// assert_eq!(x.unwrap_err().to_string(), "invalid digit found in string");
+	/// ```
+	pub fn into_anyhow(self) -> Result<A, anyhow::Error> where B: Into<anyhow::Error> {
+		self.0.map_err(Into::into)
+	}
+}
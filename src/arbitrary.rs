@@ -0,0 +1,58 @@
+use arbitrary::{Arbitrary, MaxRecursionReached, Result, Unstructured};
+
+use crate::{Strict, StrictResult};
+
+/// Generates values identically to the inner `Result`.
+///
+/// ```
+/// use arbitrary::{Arbitrary, Unstructured};
+/// use strict_result::StrictResult;
+///
+/// let mut variants = vec![];
+/// for bytes in [[0, 1, 2, 3, 4], [1, 1, 2, 3, 4]] {
+///     let x = StrictResult::<u32, u8>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+///     let y = Result::<u32, u8>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+///     assert_eq!(x, y);
+///     variants.push(x.is_ok());
+/// }
+/// assert_ne!(variants[0], variants[1]);
+/// ```
+///
+/// This includes the size hint, which reports reaching the recursion limit the same way.
+///
+/// ```
+/// use arbitrary::{size_hint, Arbitrary, MaxRecursionReached, Unstructured};
+/// use strict_result::StrictResult;
+///
+/// struct Deep;
+///
+/// impl<'a> Arbitrary<'a> for Deep {
+///     fn arbitrary(_: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+///         Ok(Deep)
+///     }
+///
+///     fn try_size_hint(depth: usize) -> Result<(usize, Option<usize>), MaxRecursionReached> {
+///         size_hint::try_recursion_guard(depth, Deep::try_size_hint)
+///     }
+/// }
+///
+/// assert!(Result::<Deep, u8>::try_size_hint(0).is_err());
+/// assert!(StrictResult::<Deep, u8>::try_size_hint(0).is_err());
+/// assert_eq!(
+///     StrictResult::<u32, u8>::try_size_hint(0).ok(),
+///     Result::<u32, u8>::try_size_hint(0).ok(),
+/// );
+/// ```
+impl<'a, A: Arbitrary<'a>, B: Arbitrary<'a>> Arbitrary<'a> for StrictResult<A, B> {
+	fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+		core::result::Result::arbitrary(u).map(Strict::strict)
+	}
+
+	fn size_hint(depth: usize) -> (usize, Option<usize>) {
+		Self::try_size_hint(depth).unwrap_or_default()
+	}
+
+	fn try_size_hint(depth: usize) -> core::result::Result<(usize, Option<usize>), MaxRecursionReached> {
+		<core::result::Result<A, B> as Arbitrary>::try_size_hint(depth)
+	}
+}
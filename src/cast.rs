@@ -0,0 +1,118 @@
+//! Conversions that rely on `StrictResult` being `#[repr(transparent)]` over `Result`.
+
+use crate::StrictResult;
+
+/// Reinterprets a reference to a `Result` as a reference to a `StrictResult`.
+///
+/// This is the inverse of [`StrictResult::as_loose`].
+///
+/// ```
+/// use strict_result::{as_strict, Strict};
+///
+/// let result = Ok::<i32, &str>(1);
+/// let strict = as_strict(&result);
+/// assert_eq!(*strict, Ok(1).strict());
+/// assert_eq!(strict.as_loose(), &result);
+/// ```
+pub const fn as_strict<A, B>(result: &Result<A, B>) -> &StrictResult<A, B> {
+	// SAFETY: StrictResult<A, B> is repr(transparent) over Result<A, B>, so a reference to one is
+	// a valid reference to the other.
+	unsafe { &*(result as *const Result<A, B> as *const StrictResult<A, B>) }
+}
+
+/// Reinterprets a slice of `Result`s as a slice of `StrictResult`s, without copying.
+///
+/// ```
+/// use strict_result::{from_result_slice, to_result_slice, Strict};
+///
+/// let results = [Ok(1), Err("bad"), Ok(3)];
+/// let strict = from_result_slice(&results);
+/// assert_eq!(strict, [Ok(1).strict(), Err("bad").strict(), Ok(3).strict()]);
+/// assert_eq!(to_result_slice(strict), results);
+/// ```
+pub fn from_result_slice<A, B>(slice: &[Result<A, B>]) -> &[StrictResult<A, B>] {
+	// SAFETY: StrictResult<A, B> is repr(transparent) over Result<A, B>, so the two have the same
+	// size, alignment and validity, and a slice of one is a valid slice of the other.
+	unsafe { &*(slice as *const [Result<A, B>] as *const [StrictResult<A, B>]) }
+}
+
+/// Reinterprets a slice of `StrictResult`s as a slice of `Result`s, without copying.
+///
+/// See [`from_result_slice`] for the inverse.
+pub fn to_result_slice<A, B>(slice: &[StrictResult<A, B>]) -> &[Result<A, B>] {
+	// SAFETY: see from_result_slice.
+	unsafe { &*(slice as *const [StrictResult<A, B>] as *const [Result<A, B>]) }
+}
+
+/// Reinterprets a mutable slice of `Result`s as a mutable slice of `StrictResult`s, without copying.
+///
+/// ```
+/// use strict_result::{from_result_slice_mut, Strict};
+///
+/// let mut results = [Ok(1), Err("bad")];
+/// from_result_slice_mut(&mut results)[1] = Ok(2).strict();
+/// assert_eq!(results, [Ok(1), Ok(2)]);
+/// ```
+pub fn from_result_slice_mut<A, B>(slice: &mut [Result<A, B>]) -> &mut [StrictResult<A, B>] {
+	// SAFETY: see from_result_slice. Both types have the same validity invariants, so writes
+	// through either view leave the other valid.
+	unsafe { &mut *(slice as *mut [Result<A, B>] as *mut [StrictResult<A, B>]) }
+}
+
+/// Reinterprets a mutable slice of `StrictResult`s as a mutable slice of `Result`s, without copying.
+///
+/// ```
+/// use strict_result::{to_result_slice_mut, Strict};
+///
+/// let mut strict = [Ok(1).strict(), Err("bad").strict()];
+/// to_result_slice_mut(&mut strict)[1] = Ok(2);
+/// assert_eq!(strict, [Ok(1).strict(), Ok(2).strict()]);
+/// ```
+pub fn to_result_slice_mut<A, B>(slice: &mut [StrictResult<A, B>]) -> &mut [Result<A, B>] {
+	// SAFETY: see from_result_slice_mut.
+	unsafe { &mut *(slice as *mut [StrictResult<A, B>] as *mut [Result<A, B>]) }
+}
+
+/// Converts a `Vec` of `Result`s into a `Vec` of `StrictResult`s, reusing the allocation.
+///
+/// ```
+/// use strict_result::{from_result_vec, Strict};
+///
+/// let mut results = Vec::with_capacity(10);
+/// results.extend([Ok(1), Err("bad")]);
+/// let ptr = results.as_ptr() as usize;
+///
+/// let strict = from_result_vec(results);
+/// assert_eq!(strict, [Ok(1).strict(), Err("bad").strict()]);
+/// assert_eq!(strict.len(), 2);
+/// assert_eq!(strict.capacity(), 10);
+/// assert_eq!(strict.as_ptr() as usize, ptr);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn from_result_vec<A, B>(vec: alloc::vec::Vec<Result<A, B>>) -> alloc::vec::Vec<StrictResult<A, B>> {
+	let mut vec = core::mem::ManuallyDrop::new(vec);
+	// SAFETY: the allocation was made for Result<A, B>, which has the same size and alignment as
+	// StrictResult<A, B> due to the transparent repr, so it can be freed as one. The original
+	// vector is not dropped, so ownership of the elements is transferred.
+	unsafe { alloc::vec::Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity()) }
+}
+
+/// Converts a `Vec` of `StrictResult`s into a `Vec` of `Result`s, reusing the allocation.
+///
+/// ```
+/// use strict_result::{to_result_vec, Strict};
+///
+/// let strict = vec![Ok(1).strict(), Err("bad").strict()];
+/// let (ptr, cap) = (strict.as_ptr() as usize, strict.capacity());
+///
+/// let results = to_result_vec(strict);
+/// assert_eq!(results, [Ok(1), Err("bad")]);
+/// assert_eq!(results.capacity(), cap);
+/// assert_eq!(results.as_ptr() as usize, ptr);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn to_result_vec<A, B>(vec: alloc::vec::Vec<StrictResult<A, B>>) -> alloc::vec::Vec<Result<A, B>> {
+	let mut vec = core::mem::ManuallyDrop::new(vec);
+	// SAFETY: see from_result_vec.
+	unsafe { alloc::vec::Vec::from_raw_parts(vec.as_mut_ptr().cast(), vec.len(), vec.capacity()) }
+}
@@ -0,0 +1,70 @@
+use core::fmt;
+
+use crate::StrictResult;
+
+impl<A, B> StrictResult<A, B> {
+	/// Wraps the error in a [`ContextError`] with a message describing what was being done.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let x = "x".parse::<i32>().strict().context("parsing the port");
+	/// let err = x.unwrap_err();
+	/// assert_eq!(err.context(), "parsing the port");
+	/// assert_eq!(err.to_string(), "parsing the port: invalid digit found in string");
+	///
+	/// assert_eq!("1".parse::<i32>().strict().context("parsing the port").unwrap(), 1);
+	/// ```
+	pub fn context(self, context: &'static str) -> StrictResult<A, ContextError<B>> {
+		self.map_err(|error| ContextError { context, error })
+	}
+}
+
+/// An error together with a static message describing what was being done.
+///
+/// Created by [`StrictResult::context`]. It displays as `context: error`, and the inner error is
+/// its [`source`](core::error::Error::source).
+///
+/// ```
+/// use std::error::Error;
+/// use strict_result::Strict;
+///
+/// let err = "x".parse::<i32>().strict().context("parsing the port").unwrap_err();
+/// assert!(err.source().unwrap().is::<std::num::ParseIntError>());
+/// assert_eq!(err.inner(), &"x".parse::<i32>().unwrap_err());
+/// assert_eq!(err.into_inner(), "x".parse::<i32>().unwrap_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContextError<B> {
+	context: &'static str,
+	error: B,
+}
+
+impl<B> ContextError<B> {
+	/// Returns the context message.
+	pub fn context(&self) -> &'static str {
+		self.context
+	}
+
+	/// Returns a reference to the inner error.
+	pub fn inner(&self) -> &B {
+		&self.error
+	}
+
+	/// Returns the inner error, discarding the context.
+	pub fn into_inner(self) -> B {
+		self.error
+	}
+}
+
+impl<B: fmt::Display> fmt::Display for ContextError<B> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}: {}", self.context, self.error)
+	}
+}
+
+impl<B: core::error::Error + 'static> core::error::Error for ContextError<B> {
+	fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+		Some(&self.error)
+	}
+}
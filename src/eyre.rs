@@ -0,0 +1,15 @@
+use crate::StrictResult;
+
+impl<A, B> StrictResult<A, B> {
+	/// Converts the error into an [`eyre::Report`], and converts into a `Result`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let x: eyre::Result<i32> = "x".parse::<i32>().strict().into_eyre();
+	/// assert_eq!(x.unwrap_err().to_string(), "invalid digit found in string");
+	/// ```
+	pub fn into_eyre(self) -> Result<A, eyre::Report> where B: Into<eyre::Report> {
+		self.0.map_err(Into::into)
+	}
+}
@@ -0,0 +1,83 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::TryFuture;
+
+use crate::{Strict, StrictResult};
+
+/// Provides the `.strict()` function on futures, to allow `.strict().await?`.
+pub trait StrictTryFutureExt: TryFuture + Sized {
+	/// Wraps the future so that it resolves to a `StrictResult`.
+	///
+	#[cfg_attr(feature = "stable", doc = "```ignore")]
+	#[cfg_attr(not(feature = "stable"), doc = "```")]
+	/// use std::future::Future;
+	/// use std::pin::pin;
+	/// use std::task::{Context, Poll, Waker};
+	/// use strict_result::StrictTryFutureExt;
+	///
+	/// fn block_on<F: Future>(f: F) -> F::Output {
+	///     let mut f = pin!(f);
+	///     let mut cx = Context::from_waker(Waker::noop());
+	///     loop {
+	///         if let Poll::Ready(v) = f.as_mut().poll(&mut cx) {
+	///             return v;
+	///         }
+	///     }
+	/// }
+	///
+	/// async fn create() -> std::io::Result<()> {
+	///     Err(std::io::ErrorKind::NotFound.into())
+	/// }
+	///
+	/// async fn outer() -> std::io::Result<i32> {
+	///     async {
+	///         create().await?;
+	///         Ok(())
+	///     }.strict().await?;
+	///     Ok(1)
+	/// }
+	///
+	/// assert_eq!(block_on(outer()).unwrap_err().kind(), std::io::ErrorKind::NotFound);
+	/// ```
+	///
+	/// The error type is not widened:
+	///
+	/// ```compile_fail
+	/// use strict_result::StrictTryFutureExt;
+	///
+	/// async fn create() -> std::io::Result<()> {
+	///     Ok(())
+	/// }
+	///
+	/// async fn outer() -> Result<(), Box<dyn std::error::Error>> {
+	///     create().strict().await?;
+	///     Ok(())
+	/// }
+	/// ```
+	fn strict(self) -> StrictFuture<Self> {
+		StrictFuture(self)
+	}
+}
+
+impl<F: TryFuture> StrictTryFutureExt for F {}
+
+/// A future that resolves to a `StrictResult`.
+///
+/// Created by [`StrictTryFutureExt::strict`].
+#[repr(transparent)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+#[derive(Clone, Copy, Debug)]
+pub struct StrictFuture<F>(F);
+
+impl<F: TryFuture> Future for StrictFuture<F> {
+	type Output = StrictResult<F::Ok, F::Error>;
+
+	fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+		// SAFETY: the inner future is structurally pinned; it is never moved out of or otherwise
+		// accessed except through this pinned reference.
+		let inner = unsafe { self.map_unchecked_mut(|s| &mut s.0) };
+		inner.try_poll(cx).map(Strict::strict)
+	}
+}
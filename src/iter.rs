@@ -0,0 +1,715 @@
+use core::iter::{FusedIterator, Product, Sum};
+
+use crate::{Strict, StrictOption, StrictResult};
+
+impl<A, B> StrictResult<A, B> {
+	/// Returns an iterator over the `Ok` value, if any.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, ()>(1).strict().iter().next(), Some(&1));
+	/// assert_eq!(Err::<i32, ()>(()).strict().iter().next(), None);
+	/// ```
+	pub fn iter(&self) -> Iter<'_, A> {
+		Iter { inner: self.0.as_ref().ok() }
+	}
+
+	/// Returns a mutable iterator over the `Ok` value, if any.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut x = Ok::<i32, ()>(1).strict();
+	/// for v in x.iter_mut() {
+	///     *v += 1;
+	/// }
+	/// assert_eq!(x, Ok(2));
+	/// ```
+	pub fn iter_mut(&mut self) -> IterMut<'_, A> {
+		IterMut { inner: self.0.as_mut().ok() }
+	}
+}
+
+/// Yields the `Ok` value, if any.
+///
+/// ```
+/// use strict_result::Strict;
+///
+/// let ok = Ok::<i32, ()>(1).strict().into_iter();
+/// assert_eq!(ok.len(), 1);
+/// assert_eq!(ok.collect::<Vec<_>>(), [1]);
+///
+/// let err = Err::<i32, ()>(()).strict().into_iter();
+/// assert_eq!(err.len(), 0);
+/// assert_eq!(err.collect::<Vec<_>>(), []);
+///
+/// let nested = [Ok(1).strict(), Err("bad").strict(), Ok(3).strict()];
+/// assert_eq!(nested.into_iter().flatten().collect::<Vec<_>>(), [1, 3]);
+/// ```
+impl<A, B> IntoIterator for StrictResult<A, B> {
+	type Item = A;
+	type IntoIter = IntoIter<A>;
+
+	fn into_iter(self) -> IntoIter<A> {
+		IntoIter { inner: self.0.ok() }
+	}
+}
+
+impl<'a, A, B> IntoIterator for &'a StrictResult<A, B> {
+	type Item = &'a A;
+	type IntoIter = Iter<'a, A>;
+
+	fn into_iter(self) -> Iter<'a, A> {
+		self.iter()
+	}
+}
+
+impl<'a, A, B> IntoIterator for &'a mut StrictResult<A, B> {
+	type Item = &'a mut A;
+	type IntoIter = IterMut<'a, A>;
+
+	fn into_iter(self) -> IterMut<'a, A> {
+		self.iter_mut()
+	}
+}
+
+impl<A> StrictOption<A> {
+	/// Returns an iterator over the `Some` value, if any.
+	///
+	/// ```
+	/// use strict_result::StrictOptionExt;
+	///
+	/// assert_eq!(Some(1).strict().iter().len(), 1);
+	/// assert_eq!(Some(1).strict().iter().next(), Some(&1));
+	/// assert_eq!(None::<i32>.strict().iter().len(), 0);
+	/// assert_eq!(None::<i32>.strict().iter().next(), None);
+	/// ```
+	pub fn iter(&self) -> Iter<'_, A> {
+		Iter { inner: self.0.as_ref() }
+	}
+
+	/// Returns a mutable iterator over the `Some` value, if any.
+	///
+	/// ```
+	/// use strict_result::StrictOptionExt;
+	///
+	/// let mut x = Some(1).strict();
+	/// for v in &mut x {
+	///     *v += 1;
+	/// }
+	/// assert_eq!(x.loose(), Some(2));
+	/// assert_eq!(None::<i32>.strict().iter_mut().len(), 0);
+	/// ```
+	pub fn iter_mut(&mut self) -> IterMut<'_, A> {
+		IterMut { inner: self.0.as_mut() }
+	}
+}
+
+/// Yields the `Some` value, if any.
+///
+/// ```
+/// use strict_result::StrictOptionExt;
+///
+/// let some = Some(1).strict().into_iter();
+/// assert_eq!(some.len(), 1);
+/// assert_eq!(some.rev().collect::<Vec<_>>(), [1]);
+///
+/// let none = None::<i32>.strict().into_iter();
+/// assert_eq!(none.len(), 0);
+/// assert_eq!(none.collect::<Vec<_>>(), []);
+/// ```
+impl<A> IntoIterator for StrictOption<A> {
+	type Item = A;
+	type IntoIter = IntoIter<A>;
+
+	fn into_iter(self) -> IntoIter<A> {
+		IntoIter { inner: self.0 }
+	}
+}
+
+impl<'a, A> IntoIterator for &'a StrictOption<A> {
+	type Item = &'a A;
+	type IntoIter = Iter<'a, A>;
+
+	fn into_iter(self) -> Iter<'a, A> {
+		self.iter()
+	}
+}
+
+impl<'a, A> IntoIterator for &'a mut StrictOption<A> {
+	type Item = &'a mut A;
+	type IntoIter = IterMut<'a, A>;
+
+	fn into_iter(self) -> IterMut<'a, A> {
+		self.iter_mut()
+	}
+}
+
+/// An iterator over a reference to the `Ok` value of a [`StrictResult`], or the `Some` value of a
+/// [`StrictOption`].
+///
+/// Created by [`StrictResult::iter`] and [`StrictOption::iter`].
+#[derive(Debug)]
+pub struct Iter<'a, A> {
+	inner: Option<&'a A>,
+}
+
+impl<A> Clone for Iter<'_, A> {
+	fn clone(&self) -> Self {
+		Iter { inner: self.inner }
+	}
+}
+
+impl<'a, A> Iterator for Iter<'a, A> {
+	type Item = &'a A;
+
+	fn next(&mut self) -> Option<&'a A> {
+		self.inner.take()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let n = self.inner.is_some() as usize;
+		(n, Some(n))
+	}
+}
+
+impl<'a, A> DoubleEndedIterator for Iter<'a, A> {
+	fn next_back(&mut self) -> Option<&'a A> {
+		self.inner.take()
+	}
+}
+
+impl<A> ExactSizeIterator for Iter<'_, A> {}
+
+impl<A> FusedIterator for Iter<'_, A> {}
+
+/// An iterator over a mutable reference to the `Ok` value of a [`StrictResult`], or the `Some`
+/// value of a [`StrictOption`].
+///
+/// Created by [`StrictResult::iter_mut`] and [`StrictOption::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, A> {
+	inner: Option<&'a mut A>,
+}
+
+impl<'a, A> Iterator for IterMut<'a, A> {
+	type Item = &'a mut A;
+
+	fn next(&mut self) -> Option<&'a mut A> {
+		self.inner.take()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let n = self.inner.is_some() as usize;
+		(n, Some(n))
+	}
+}
+
+impl<'a, A> DoubleEndedIterator for IterMut<'a, A> {
+	fn next_back(&mut self) -> Option<&'a mut A> {
+		self.inner.take()
+	}
+}
+
+impl<A> ExactSizeIterator for IterMut<'_, A> {}
+
+impl<A> FusedIterator for IterMut<'_, A> {}
+
+/// An iterator over the `Ok` value of a [`StrictResult`], or the `Some` value of a [`StrictOption`].
+///
+/// Created by the [`into_iter`](IntoIterator::into_iter) method on [`StrictResult`] and
+/// [`StrictOption`].
+#[derive(Clone, Debug)]
+pub struct IntoIter<A> {
+	inner: Option<A>,
+}
+
+impl<A> Iterator for IntoIter<A> {
+	type Item = A;
+
+	fn next(&mut self) -> Option<A> {
+		self.inner.take()
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let n = self.inner.is_some() as usize;
+		(n, Some(n))
+	}
+}
+
+impl<A> DoubleEndedIterator for IntoIter<A> {
+	fn next_back(&mut self) -> Option<A> {
+		self.inner.take()
+	}
+}
+
+impl<A> ExactSizeIterator for IntoIter<A> {}
+
+impl<A> FusedIterator for IntoIter<A> {}
+
+/// Collects the `Ok` values, stopping at the first `Err`.
+///
+/// The error is carried through as is, without any conversion.
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use strict_result::{Strict, StrictResult};
+///
+/// let all = [Ok(1).strict(), Ok(2).strict(), Ok(1).strict()];
+/// let v: StrictResult<Vec<i32>, &str> = all.into_iter().collect();
+/// assert_eq!(v, Ok(vec![1, 2, 1]));
+/// let s: StrictResult<BTreeSet<i32>, &str> = all.into_iter().collect();
+/// assert_eq!(s, Ok(BTreeSet::from([1, 2])));
+///
+/// let mut seen = 0;
+/// let some = [Ok(1).strict(), Err("bad").strict(), Ok(3).strict(), Err("worse").strict()];
+/// let v: StrictResult<Vec<i32>, &str> = some.into_iter().inspect(|_| seen += 1).collect();
+/// assert_eq!(v, Err("bad"));
+/// assert_eq!(seen, 2);
+/// ```
+impl<A, B, C: FromIterator<A>> FromIterator<StrictResult<A, B>> for StrictResult<C, B> {
+	fn from_iter<I: IntoIterator<Item = StrictResult<A, B>>>(iter: I) -> Self {
+		iter.into_iter().map(StrictResult::loose).collect::<Result<C, B>>().strict()
+	}
+}
+
+/// Provides the `.try_collect_strict()` function for iterators over `StrictResult`s.
+pub trait StrictIteratorExt<A, B>: Iterator<Item = StrictResult<A, B>> + Sized {
+	/// Collects the `Ok` values, stopping at the first `Err`.
+	///
+	/// This is the same as `.collect::<StrictResult<C, B>>()`, but only the collection needs to be
+	/// named.
+	///
+	/// ```
+	/// use strict_result::{Strict, StrictIteratorExt};
+	///
+	/// let parse = |s: &str| s.parse::<i32>().strict();
+	/// let v = ["1", "2", "3"].into_iter().map(parse).try_collect_strict::<Vec<_>>();
+	/// assert_eq!(v, Ok(vec![1, 2, 3]));
+	///
+	/// let mut seen = 0;
+	/// let v = ["1", "x", "3"].into_iter().inspect(|_| seen += 1).map(parse).try_collect_strict::<Vec<_>>();
+	/// assert!(v.is_err());
+	/// assert_eq!(seen, 2);
+	/// ```
+	fn try_collect_strict<C: FromIterator<A>>(self) -> StrictResult<C, B>;
+}
+
+impl<A, B, I: Iterator<Item = StrictResult<A, B>>> StrictIteratorExt<A, B> for I {
+	fn try_collect_strict<C: FromIterator<A>>(self) -> StrictResult<C, B> {
+		self.collect()
+	}
+}
+
+/// Provides the `.map_while_strict()` function for iterators.
+pub trait MapWhileStrictExt: Iterator + Sized {
+	/// Maps each item with a fallible function, stopping at the first `Err`.
+	///
+	/// The error is kept in the returned iterator, and can be retrieved with
+	/// [`finish`](MapWhileStrict::finish) once iteration is done.
+	///
+	/// ```
+	/// use strict_result::{MapWhileStrictExt, Strict};
+	///
+	/// let mut it = ["1", "2", "x", "4"].into_iter().map_while_strict(|s| s.parse::<i32>().strict());
+	/// assert_eq!(it.by_ref().collect::<Vec<_>>(), [1, 2]);
+	/// assert_eq!(it.next(), None);
+	/// assert!(it.err().is_some());
+	/// assert!(it.finish().is_err());
+	///
+	/// let mut it = ["1", "2"].into_iter().map_while_strict(|s| s.parse::<i32>().strict());
+	/// assert_eq!(it.by_ref().collect::<Vec<_>>(), [1, 2]);
+	/// assert_eq!(it.finish(), Ok(()));
+	/// ```
+	fn map_while_strict<U, B, F: FnMut(Self::Item) -> StrictResult<U, B>>(self, f: F) -> MapWhileStrict<Self, F, B>;
+}
+
+impl<I: Iterator> MapWhileStrictExt for I {
+	fn map_while_strict<U, B, F: FnMut(I::Item) -> StrictResult<U, B>>(self, f: F) -> MapWhileStrict<I, F, B> {
+		MapWhileStrict { iter: self, f, err: None }
+	}
+}
+
+/// An iterator that maps items with a fallible function, stopping at the first `Err`.
+///
+/// Created by [`MapWhileStrictExt::map_while_strict`].
+///
+/// ```
+/// use strict_result::{MapWhileStrictExt, Strict};
+///
+/// let mut it = [1, 2].into_iter().map_while_strict(|v| Err::<i32, _>(v).strict());
+/// it.next();
+/// assert_eq!(format!("{it:?}"), "MapWhileStrict { iter: IntoIter([2]), err: Some(1) }");
+/// ```
+#[derive(Clone)]
+pub struct MapWhileStrict<I, F, B> {
+	iter: I,
+	f: F,
+	err: Option<B>,
+}
+
+impl<I: core::fmt::Debug, F, B: core::fmt::Debug> core::fmt::Debug for MapWhileStrict<I, F, B> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("MapWhileStrict").field("iter", &self.iter).field("err", &self.err).finish()
+	}
+}
+
+impl<I, F, B> MapWhileStrict<I, F, B> {
+	/// Returns the error that stopped the iteration, if any.
+	pub fn err(&self) -> Option<&B> {
+		self.err.as_ref()
+	}
+
+	/// Consumes the iterator, returning the error that stopped the iteration, if any.
+	pub fn finish(self) -> StrictResult<(), B> {
+		match self.err {
+			Some(e) => Err(e).strict(),
+			None => Ok(()).strict(),
+		}
+	}
+}
+
+impl<U, B, I: Iterator, F: FnMut(I::Item) -> StrictResult<U, B>> Iterator for MapWhileStrict<I, F, B> {
+	type Item = U;
+
+	fn next(&mut self) -> Option<U> {
+		if self.err.is_some() {
+			return None;
+		}
+		match (self.f)(self.iter.next()?).0 {
+			Ok(v) => Some(v),
+			Err(e) => {
+				self.err = Some(e);
+				None
+			}
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.err.is_some() {
+			(0, Some(0))
+		} else {
+			(0, self.iter.size_hint().1)
+		}
+	}
+}
+
+/// Sums the `Ok` values, stopping at the first `Err`.
+///
+/// ```
+/// use strict_result::{Strict, StrictResult};
+///
+/// let parse = |s: &str| s.parse::<i64>().strict();
+/// let total: StrictResult<i64, _> = ["1", "2", "3"].into_iter().map(parse).sum();
+/// assert_eq!(total, Ok(6));
+/// let total: StrictResult<i64, _> = ["1", "x", "3"].into_iter().map(parse).sum();
+/// assert!(total.is_err());
+/// ```
+impl<A, B, C: Sum<A>> Sum<StrictResult<A, B>> for StrictResult<C, B> {
+	fn sum<I: Iterator<Item = StrictResult<A, B>>>(iter: I) -> Self {
+		iter.map(StrictResult::loose).sum::<Result<C, B>>().strict()
+	}
+}
+
+/// Multiplies the `Ok` values, stopping at the first `Err`.
+///
+/// ```
+/// use strict_result::{Strict, StrictResult};
+///
+/// let parse = |s: &str| s.parse::<i64>().strict();
+/// let total: StrictResult<i64, _> = ["2", "3", "4"].into_iter().map(parse).product();
+/// assert_eq!(total, Ok(24));
+/// let total: StrictResult<i64, _> = ["2", "x", "4"].into_iter().map(parse).product();
+/// assert!(total.is_err());
+/// ```
+impl<A, B, C: Product<A>> Product<StrictResult<A, B>> for StrictResult<C, B> {
+	fn product<I: Iterator<Item = StrictResult<A, B>>>(iter: I) -> Self {
+		iter.map(StrictResult::loose).product::<Result<C, B>>().strict()
+	}
+}
+
+/// Extends the `Ok` value, stopping at the first `Err`, which then replaces it.
+///
+/// If `self` is already an `Err`, the iterator is not consumed at all.
+///
+/// ```
+/// use strict_result::{Strict, StrictResult};
+///
+/// let mut acc: StrictResult<Vec<i32>, &str> = Ok(Vec::with_capacity(8)).strict();
+/// acc.extend([Ok(1).strict(), Ok(2).strict()]);
+/// assert_eq!(acc, Ok(vec![1, 2]));
+///
+/// acc.extend([Ok(3).strict(), Err("bad").strict(), Ok(4).strict()]);
+/// assert_eq!(acc, Err("bad"));
+///
+/// acc.extend([Ok(5).strict()]);
+/// assert_eq!(acc, Err("bad"));
+/// ```
+impl<A, B, C: Extend<A>> Extend<StrictResult<A, B>> for StrictResult<C, B> {
+	fn extend<I: IntoIterator<Item = StrictResult<A, B>>>(&mut self, iter: I) {
+		if let Ok(c) = &mut self.0 {
+			let mut err = None;
+			c.extend(iter.into_iter().map_while(|r| match r.0 {
+				Ok(v) => Some(v),
+				Err(e) => {
+					err = Some(e);
+					None
+				}
+			}));
+			if let Some(e) = err {
+				self.0 = Err(e);
+			}
+		}
+	}
+}
+
+/// Folds an iterator with a fallible function, stopping at the first `Err`.
+///
+/// ```
+/// use strict_result::{try_fold_strict, Strict};
+///
+/// let add = |acc: i32, v: &i32| if *v < 0 { Err(*v) } else { Ok(acc + v) }.strict();
+/// assert_eq!(try_fold_strict(&vec![1, 2, 3], 0, add), Ok(6));
+///
+/// let mut seen = vec![];
+/// let r = try_fold_strict(&vec![1, -2, 3], 0, |acc, v| { seen.push(*v); add(acc, v) });
+/// assert_eq!(r, Err(-2));
+/// assert_eq!(seen, [1, -2]);
+/// ```
+pub fn try_fold_strict<I: IntoIterator, Acc, E>(
+	iter: I,
+	init: Acc,
+	mut f: impl FnMut(Acc, I::Item) -> StrictResult<Acc, E>,
+) -> StrictResult<Acc, E> {
+	let mut acc = init;
+	for item in iter {
+		match f(acc, item).0 {
+			Ok(v) => acc = v,
+			Err(e) => return Err(e).strict(),
+		}
+	}
+	Ok(acc).strict()
+}
+
+/// Calls a fallible function on each item of an iterator, stopping at the first `Err`.
+///
+/// ```
+/// use strict_result::{try_for_each_strict, Strict};
+///
+/// let mut seen = vec![];
+/// let r = try_for_each_strict(vec![1, -2, 3], |v| {
+///     seen.push(v);
+///     if v < 0 { Err(v) } else { Ok(()) }.strict()
+/// });
+/// assert_eq!(r, Err(-2));
+/// assert_eq!(seen, [1, -2]);
+/// ```
+pub fn try_for_each_strict<I: IntoIterator, E>(
+	iter: I,
+	mut f: impl FnMut(I::Item) -> StrictResult<(), E>,
+) -> StrictResult<(), E> {
+	try_fold_strict(iter, (), |(), item| f(item))
+}
+
+/// Updates a state with a fallible function for each item of an iterator, stopping at the first
+/// `Err`.
+///
+/// On success the final state is returned. On failure the partially updated state is dropped.
+///
+/// ```
+/// use strict_result::{scan_strict, Strict};
+///
+/// let push = |acc: &mut String, c: char| {
+///     if c.is_ascii_digit() { Err(c) } else { acc.push(c.to_ascii_uppercase()); Ok(()) }.strict()
+/// };
+/// assert_eq!(scan_strict("abc".chars(), String::new(), push), Ok("ABC".to_string()));
+///
+/// let mut seen = vec![];
+/// let r = scan_strict("ab1c".chars(), String::new(), |acc, c| { seen.push(c); push(acc, c) });
+/// assert_eq!(r, Err('1'));
+/// assert_eq!(seen, ['a', 'b', '1']);
+/// ```
+pub fn scan_strict<I: IntoIterator, St, E>(
+	iter: I,
+	init: St,
+	mut f: impl FnMut(&mut St, I::Item) -> StrictResult<(), E>,
+) -> StrictResult<St, E> {
+	try_fold_strict(iter, init, |mut st, item| f(&mut st, item).map(|()| st))
+}
+
+/// Creates an iterator where each item is computed from the previous one with a fallible
+/// function, like [`core::iter::successors`].
+///
+/// Iteration ends when `succ` returns `Ok(None)` or an `Err`. The error is kept in the iterator,
+/// and can be retrieved with [`finish`](SuccessorsStrict::finish).
+///
+/// ```
+/// use strict_result::{successors_strict, Strict};
+///
+/// let mut it = successors_strict(Some(1u8), |v| Ok::<_, ()>(v.checked_mul(10)).strict());
+/// assert_eq!(it.by_ref().collect::<Vec<_>>(), [1, 10, 100]);
+/// assert_eq!(it.finish(), Ok(()));
+///
+/// let mut it = successors_strict(Some(1), |v| if *v < 3 { Ok(Some(v + 1)) } else { Err("too big") }.strict());
+/// assert_eq!(it.by_ref().collect::<Vec<_>>(), [1, 2, 3]);
+/// assert_eq!(it.err(), Some(&"too big"));
+/// assert_eq!(it.finish(), Err("too big"));
+/// ```
+pub fn successors_strict<A, B, F: FnMut(&A) -> StrictResult<Option<A>, B>>(first: Option<A>, succ: F) -> SuccessorsStrict<A, F, B> {
+	SuccessorsStrict { next: first, succ, err: None }
+}
+
+/// An iterator where each item is computed from the previous one with a fallible function.
+///
+/// Created by [`successors_strict`].
+///
+/// ```
+/// use strict_result::{successors_strict, Strict};
+///
+/// let mut it = successors_strict(Some(1), |v| Ok::<_, ()>(Some(v + 1)).strict());
+/// it.next();
+/// assert_eq!(format!("{it:?}"), "SuccessorsStrict { next: Some(2), err: None }");
+/// ```
+#[derive(Clone)]
+pub struct SuccessorsStrict<A, F, B> {
+	next: Option<A>,
+	succ: F,
+	err: Option<B>,
+}
+
+impl<A: core::fmt::Debug, F, B: core::fmt::Debug> core::fmt::Debug for SuccessorsStrict<A, F, B> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_struct("SuccessorsStrict").field("next", &self.next).field("err", &self.err).finish()
+	}
+}
+
+impl<A, F, B> SuccessorsStrict<A, F, B> {
+	/// Returns the error that stopped the iteration, if any.
+	pub fn err(&self) -> Option<&B> {
+		self.err.as_ref()
+	}
+
+	/// Consumes the iterator, returning the error that stopped the iteration, if any.
+	pub fn finish(self) -> StrictResult<(), B> {
+		match self.err {
+			Some(e) => Err(e).strict(),
+			None => Ok(()).strict(),
+		}
+	}
+}
+
+impl<A, B, F: FnMut(&A) -> StrictResult<Option<A>, B>> Iterator for SuccessorsStrict<A, F, B> {
+	type Item = A;
+
+	fn next(&mut self) -> Option<A> {
+		let item = self.next.take()?;
+		match (self.succ)(&item).0 {
+			Ok(next) => self.next = next,
+			Err(e) => self.err = Some(e),
+		}
+		Some(item)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.next.is_some() { (1, None) } else { (0, Some(0)) }
+	}
+}
+
+impl<A, B, F: FnMut(&A) -> StrictResult<Option<A>, B>> FusedIterator for SuccessorsStrict<A, F, B> {}
+
+/// Reduces an iterator with a fallible function, stopping at the first `Err`.
+///
+/// Returns `Ok(None)` if the iterator is empty.
+///
+/// ```
+/// use strict_result::{reduce_strict, Strict};
+///
+/// let add = |a: u8, b: u8| a.checked_add(b).ok_or("overflow").strict();
+/// assert_eq!(reduce_strict(Vec::<u8>::new(), add), Ok(None));
+/// assert_eq!(reduce_strict([1], add), Ok(Some(1)));
+/// assert_eq!(reduce_strict([1, 2, 3], add), Ok(Some(6)));
+///
+/// let mut calls = 0;
+/// let r = reduce_strict([1, 255, 3, 4], |a, b| { calls += 1; add(a, b) });
+/// assert_eq!(r, Err("overflow"));
+/// assert_eq!(calls, 1);
+/// ```
+pub fn reduce_strict<I: IntoIterator, E>(
+	iter: I,
+	f: impl FnMut(I::Item, I::Item) -> StrictResult<I::Item, E>,
+) -> StrictResult<Option<I::Item>, E> {
+	let mut iter = iter.into_iter();
+	match iter.next() {
+		Some(first) => try_fold_strict(iter, first, f).map(Some),
+		None => Ok(None).strict(),
+	}
+}
+
+/// Calls `f` with an iterator over the `Ok` values, stopping at the first `Err`.
+///
+/// If an `Err` is encountered, the iterator ends early and the error is returned instead of the
+/// result of `f`. The error is carried through as is, without any conversion.
+///
+/// ```
+/// use strict_result::{process_strict, Strict};
+///
+/// let parse = |s: &str| s.parse::<i32>().strict();
+/// assert_eq!(process_strict(["1", "2", "3"].map(parse), |it| it.max()), Ok(Some(3)));
+///
+/// let mut seen = vec![];
+/// let r = process_strict(["1", "x", "3"].map(parse), |it| it.for_each(|v| seen.push(v)));
+/// assert!(r.is_err());
+/// assert_eq!(seen, [1]);
+/// ```
+pub fn process_strict<I, A, B, R>(
+	iter: I,
+	f: impl FnOnce(ProcessStrict<'_, I::IntoIter, B>) -> R,
+) -> StrictResult<R, B>
+where
+	I: IntoIterator<Item = StrictResult<A, B>>,
+{
+	let mut err = None;
+	let r = f(ProcessStrict { iter: iter.into_iter(), err: &mut err });
+	match err {
+		Some(e) => Err(e).strict(),
+		None => Ok(r).strict(),
+	}
+}
+
+/// An iterator over the `Ok` values of an iterator of [`StrictResult`]s, which ends at the first
+/// `Err`.
+///
+/// Created by [`process_strict`].
+#[derive(Debug)]
+pub struct ProcessStrict<'a, I, B> {
+	iter: I,
+	err: &'a mut Option<B>,
+}
+
+impl<A, B, I: Iterator<Item = StrictResult<A, B>>> Iterator for ProcessStrict<'_, I, B> {
+	type Item = A;
+
+	fn next(&mut self) -> Option<A> {
+		if self.err.is_some() {
+			return None;
+		}
+		match self.iter.next()?.0 {
+			Ok(v) => Some(v),
+			Err(e) => {
+				*self.err = Some(e);
+				None
+			}
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		if self.err.is_some() {
+			(0, Some(0))
+		} else {
+			(0, self.iter.size_hint().1)
+		}
+	}
+}
+
+impl<A, B, I: FusedIterator<Item = StrictResult<A, B>>> FusedIterator for ProcessStrict<'_, I, B> {}
@@ -1,6 +1,7 @@
 #![warn(missing_docs)]
 #![no_std]
-#![feature(try_trait_v2)]
+#![cfg_attr(not(feature = "stable"), feature(try_trait_v2, try_trait_v2_residual))]
+#![cfg_attr(all(feature = "yeet", not(feature = "stable")), feature(try_trait_v2_yeet))]
 
 /*!
 
@@ -35,8 +36,10 @@ passthrough(|| {
 ```
 
 In this case we can use `.strict()?` to require that the error type is equal to the outer one.
-
-```
+*/
+#![cfg_attr(feature = "stable", doc = "```ignore")]
+#![cfg_attr(not(feature = "stable"), doc = "```")]
+/*!
 # fn passthrough<T>(f: impl FnOnce() -> T) -> T {
 #     f()
 # }
@@ -51,16 +54,99 @@ passthrough(|| {
 # }
 ```
 
-This crate uses the `try_trait_v2` feature, and thus requires nightly.
+This crate uses the `try_trait_v2` feature, and thus requires nightly. The `stable` feature removes
+everything that depends on it, which is `?` on [`StrictResult`] and [`StrictOption`], `strict!`,
+`NoneError`, `Widen`, `try` blocks, the `yeet` feature, and the `#[strict]` and
+`strict_block!` macros. The [`strict_try!`] macro is provided as a replacement for `?`.
+
+# Features
+
+- `alloc`: adds conversions between `Vec`s of `Result` and `StrictResult`, as well as `expect_with`,
+  `into_boxed`, `err_to_string`, `map_err_borrowed` and `sort_strict_results`.
+- `std`: implies `alloc`, and implements `Termination`, so that `main` can return a `StrictResult`.
+- `macros`: adds a `#[strict]` attribute and a `strict_block!` macro, which make every `?` in a
+  function or block strict.
+- `rayon`: implements `FromParallelIterator`, like `FromIterator`.
+- `anyhow`, `eyre`: add `into_anyhow` and `into_eyre`, for explicitly erasing the error type.
+- `futures`: adds `.strict()` on futures resolving to a `Result`.
+- `yeet`: allows `do yeet` in functions returning `StrictResult`. This requires the
+  `yeet_expr` feature in the calling crate.
+- `stable`: builds on stable Rust, at the cost of the APIs listed above.
+- `serde`: implements `Serialize` and `Deserialize`, identically to the inner type.
+- `arbitrary`: implements `Arbitrary`, identically to the inner type, for fuzzing.
+- `proptest`: adds `strict_result_strategy`, for generating `StrictResult`s in property tests.
+- `deref`: implements `Deref<Target = Result>`, so that `Result` methods can be called directly.
+  Any method not provided by `StrictResult` itself then silently returns a plain `Result`, so
+  this is off by default.
 */
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 mod seal {
 	pub trait Sealed {}
 	impl<A, B> Sealed for Result<A, B> {}
+	impl<A> Sealed for Option<A> {}
+	impl<A, B> Sealed for crate::StrictResult<A, B> {}
 }
 
+mod iter;
+pub use iter::{IntoIter, Iter, IterMut, MapWhileStrict, MapWhileStrictExt, ProcessStrict, StrictIteratorExt, SuccessorsStrict};
+pub use iter::{process_strict, reduce_strict, scan_strict, successors_strict, try_fold_strict, try_for_each_strict};
+
+mod option;
+pub use option::{StrictOption, StrictOptionExt};
+
+#[cfg(all(feature = "macros", not(feature = "stable")))]
+pub mod macros {
+	//! Procedural macros, enabled by the `macros` feature.
+	pub use strict_result_macros::{strict, strict_block};
+}
+
+mod context;
+pub use context::ContextError;
+
+mod cast;
+pub use cast::{as_strict, from_result_slice, from_result_slice_mut, to_result_slice, to_result_slice_mut};
+#[cfg(feature = "alloc")]
+pub use cast::{from_result_vec, to_result_vec};
+
+#[cfg(not(feature = "stable"))]
+mod widen;
+#[cfg(not(feature = "stable"))]
+pub use widen::Widen;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "rayon")]
+mod rayon;
+
+#[cfg(feature = "anyhow")]
+mod anyhow;
+
+#[cfg(feature = "eyre")]
+mod eyre;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "proptest")]
+mod strategy;
+#[cfg(feature = "proptest")]
+pub use strategy::strict_result_strategy;
+
+#[cfg(feature = "futures")]
+mod future;
+#[cfg(feature = "futures")]
+pub use future::{StrictFuture, StrictTryFutureExt};
+
 use core::convert::Infallible;
-use core::ops::{ControlFlow, Try, FromResidual};
+use core::ops::ControlFlow;
+#[cfg(not(feature = "stable"))]
+use core::ops::{Try, FromResidual, Residual};
 
 /// A wrapper around `Result` that suppresses the implicit `.into()` when using the `?` operator.
 ///
@@ -70,11 +156,78 @@ use core::ops::{ControlFlow, Try, FromResidual};
 /// It is in general not recommended to have this function in function signatures; the recommended
 /// usage is to use `.strict()?` after function calls to suppress the casting. Still, this type is
 /// exposed in it might be useful.
+///
+/// Like `Result`, it is `Clone` and `Copy` whenever both of its type parameters are.
+///
+/// ```
+/// use strict_result::Strict;
+///
+/// let ok = Ok::<String, String>("a".into()).strict();
+/// let err = Err::<String, String>("b".into()).strict();
+/// assert_eq!(ok.clone().loose(), Ok("a".into()));
+/// assert_eq!(err.clone().loose(), Err("b".into()));
+///
+/// let copy = Ok::<i32, ()>(1).strict();
+/// let moved = copy;
+/// assert_eq!(copy.loose(), moved.loose());
+/// ```
+///
+/// Comparisons also delegate to the inner `Result`, so `Ok` values sort before `Err` values.
+///
+/// ```
+/// use strict_result::Strict;
+///
+/// assert_eq!(Ok::<i32, i32>(5).strict(), Ok(5).strict());
+/// assert_ne!(Ok::<i32, i32>(5).strict(), Err(5).strict());
+/// assert_ne!(Err::<i32, i32>(5).strict(), Ok(5).strict());
+/// assert_ne!(Ok::<(), ()>(()).strict(), Err(()).strict());
+/// assert!(Ok::<i32, i32>(9).strict() < Err(1).strict());
+///
+/// let mut values = [Err(1).strict(), Ok(2).strict(), Err(0).strict(), Ok(1).strict()];
+/// values.sort();
+/// assert_eq!(values, [Ok(1).strict(), Ok(2).strict(), Err(0).strict(), Err(1).strict()]);
+/// ```
+///
+/// Hashing is consistent with equality, so it can be used as a map key.
+///
+/// ```
+/// use std::collections::HashMap;
+/// use strict_result::Strict;
+///
+/// let mut map = HashMap::new();
+/// map.insert(Ok::<i32, &str>(1).strict(), "one");
+/// map.insert(Err("bad").strict(), "bad");
+/// assert_eq!(map[&Ok(1).strict()], "one");
+/// assert_eq!(map[&Err("bad").strict()], "bad");
+/// assert_eq!(map.get(&Ok(2).strict()), None);
+/// ```
 #[repr(transparent)]
-#[must_use = "the contained `Result` may be an `Err` variant, which should be handled"]
+#[must_use = "this `StrictResult` may be an `Err` variant, which should be handled with `?`, `.loose()`, `.ok()` or `.unwrap()`, or ignored with `.discard()`"]
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct StrictResult<A, B>(Result<A, B>);
 
+/// Formats the inner `Result`, followed by `.strict()` to distinguish it from a bare `Result`.
+///
+/// ```
+/// use strict_result::Strict;
+///
+/// assert_eq!(format!("{:?}", Ok::<i32, ()>(1).strict()), "Ok(1).strict()");
+/// assert_eq!(format!("{:?}", Err::<(), _>("oops").strict()), r#"Err("oops").strict()"#);
+/// ```
+///
+/// The alternate form `{:#?}` is forwarded to the inner `Result`:
+///
+/// ```
+/// use strict_result::{Strict, StrictResult};
+///
+/// let x: StrictResult<StrictResult<i32, ()>, ()> = Ok(Ok(1).strict()).strict();
+/// assert_eq!(format!("{:#?}", x), "\
+/// Ok(
+///     Ok(
+///         1,
+///     ).strict(),
+/// ).strict()");
+/// ```
 impl<A: core::fmt::Debug, B: core::fmt::Debug> core::fmt::Debug for StrictResult<A, B> {
 	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
 		self.0.fmt(f)?;
@@ -82,11 +235,144 @@ impl<A: core::fmt::Debug, B: core::fmt::Debug> core::fmt::Debug for StrictResult
 	}
 }
 
+/// Allows comparing a `StrictResult` directly against a `Result`.
+///
+/// ```
+/// use strict_result::Strict;
+///
+/// assert_eq!(Ok::<i32, ()>(1).strict(), Ok(1));
+/// assert_ne!(Err::<i32, i32>(1).strict(), Ok(1));
+/// assert_ne!(Ok::<i32, i32>(1).strict(), Err(1));
+/// assert_ne!(Ok::<(), ()>(()).strict(), Err(()));
+/// ```
+impl<A: PartialEq, B: PartialEq> PartialEq<Result<A, B>> for StrictResult<A, B> {
+	fn eq(&self, other: &Result<A, B>) -> bool {
+		self.0 == *other
+	}
+}
+
+/// Allows comparing a `Result` directly against a `StrictResult`.
+///
+/// ```
+/// use strict_result::Strict;
+///
+/// assert_eq!(Err::<(), i32>(1), Err(1).strict());
+/// assert_ne!(Ok::<i32, i32>(1), Err(1).strict());
+/// assert_ne!(Err::<i32, i32>(1), Ok(1).strict());
+/// assert_ne!(Err::<(), ()>(()), Ok(()).strict());
+/// ```
+impl<A: PartialEq, B: PartialEq> PartialEq<StrictResult<A, B>> for Result<A, B> {
+	fn eq(&self, other: &StrictResult<A, B>) -> bool {
+		*self == other.0
+	}
+}
+
+/// Equivalent to [`Strict::strict`].
+///
+/// ```
+/// use strict_result::{Strict, StrictResult};
+///
+/// let x: StrictResult<i32, ()> = Ok(1).into();
+/// assert_eq!(x, Ok(1).strict());
+///
+/// fn takes_strict(r: impl Into<StrictResult<i32, &'static str>>) -> bool {
+///     r.into().is_ok()
+/// }
+/// assert!(takes_strict(Ok(1)));
+/// assert!(!takes_strict(Err("bad")));
+/// ```
+impl<A, B> From<Result<A, B>> for StrictResult<A, B> {
+	fn from(r: Result<A, B>) -> Self {
+		r.strict()
+	}
+}
+
+/// Equivalent to [`StrictResult::loose`].
+///
+/// ```
+/// use strict_result::Strict;
+///
+/// let x: Result<i32, ()> = Ok(1).strict().into();
+/// assert_eq!(x, Ok(1));
+///
+/// fn takes_loose(r: impl Into<Result<i32, &'static str>>) -> bool {
+///     r.into().is_ok()
+/// }
+/// assert!(takes_loose(Ok(1).strict()));
+/// assert!(!takes_loose(Err("bad").strict()));
+/// ```
+impl<A, B> From<StrictResult<A, B>> for Result<A, B> {
+	fn from(r: StrictResult<A, B>) -> Self {
+		r.loose()
+	}
+}
+
+/// Allows calling `Result` methods directly, and coercing `&StrictResult` into `&Result`.
+///
+/// Methods that `StrictResult` provides itself take precedence, and keep the result strict. Any
+/// other method is called on the inner `Result`, and returns plain `Result`s, which is easy to
+/// overlook. For this reason, this is only enabled with the `deref` feature.
+///
+/// ```
+/// use strict_result::Strict;
+///
+/// fn takes_result(r: &Result<i32, &str>) -> bool {
+///     r.is_ok()
+/// }
+///
+/// let x = Ok::<i32, &str>(1).strict();
+/// assert!(takes_result(&x));
+/// assert_eq!((*x).map(|v| v + 1), Ok(2));
+/// ```
+#[cfg(feature = "deref")]
+impl<A, B> core::ops::Deref for StrictResult<A, B> {
+	type Target = Result<A, B>;
+
+	fn deref(&self) -> &Result<A, B> {
+		&self.0
+	}
+}
+
+/// Converts into an `Option` of the `Ok` value, like [`StrictResult::ok`].
+///
+/// ```
+/// use strict_result::Strict;
+///
+/// let x: Option<i32> = Ok::<i32, &str>(1).strict().into();
+/// assert_eq!(x, Some(1));
+/// let x: Option<i32> = Err::<i32, &str>("bad").strict().into();
+/// assert_eq!(x, None);
+/// ```
+impl<A, B> From<StrictResult<A, B>> for Option<A> {
+	fn from(r: StrictResult<A, B>) -> Self {
+		r.ok()
+	}
+}
+
+/// Returns `Ok` with the default value.
+///
+/// ```
+/// use strict_result::StrictResult;
+///
+/// assert_eq!(StrictResult::<i32, String>::default(), Ok(0));
+///
+/// #[derive(Default)]
+/// struct Slot {
+///     value: StrictResult<Vec<i32>, String>,
+/// }
+/// assert_eq!(Slot::default().value, Ok(vec![]));
+/// ```
+impl<A: Default, B> Default for StrictResult<A, B> {
+	fn default() -> Self {
+		Ok(A::default()).strict()
+	}
+}
+
 /// Provides the `.strict()?` function.
 ///
 /// See the [top-level description](crate) for details.
 pub trait Strict<A, B>: seal::Sealed {
-	/// Converts a `Result` into a `StrictResult`.
+	/// Converts a `Result` into a `StrictResult`, or returns a `StrictResult` unchanged.
 	fn strict(self) -> StrictResult<A, B>;
 }
 
@@ -96,13 +382,1711 @@ impl<A, B> Strict<A, B> for Result<A, B> {
 	}
 }
 
+/// Returns the `StrictResult` unchanged, so that `.strict()` can be used without knowing whether
+/// the value is already strict.
+///
+#[cfg_attr(feature = "stable", doc = "```ignore")]
+#[cfg_attr(not(feature = "stable"), doc = "```")]
+/// use strict_result::Strict;
+///
+/// fn strict() -> Result<i32, ()> {
+///     let x = Ok(1).strict().strict()?;
+///     Ok(x)
+/// }
+/// assert_eq!(strict(), Ok(1));
+/// ```
+impl<A, B> Strict<A, B> for StrictResult<A, B> {
+	fn strict(self) -> StrictResult<A, B> {
+		self
+	}
+}
+
+/// Creates an `Ok` `StrictResult`.
+///
+/// Unlike [`Strict::strict`], this can be used in `const` contexts.
+///
+/// ```
+/// use strict_result::{strict_ok, StrictResult};
+///
+/// const X: StrictResult<i32, ()> = strict_ok(1);
+/// const { assert!(X.is_ok()) };
+/// assert_eq!(X.loose(), Ok(1));
+/// ```
+pub const fn strict_ok<A, B>(a: A) -> StrictResult<A, B> {
+	StrictResult(Ok(a))
+}
+
+/// Creates an `Err` `StrictResult`.
+///
+/// Unlike [`Strict::strict`], this can be used in `const` contexts.
+///
+/// ```
+/// use strict_result::{strict_err, StrictResult};
+///
+/// const X: StrictResult<(), i32> = strict_err(1);
+/// const { assert!(X.is_err()) };
+/// const Y: Result<(), i32> = X.loose();
+/// assert_eq!(Y, Err(1));
+/// ```
+pub const fn strict_err<A, B>(b: B) -> StrictResult<A, B> {
+	StrictResult(Err(b))
+}
+
+/// Converts an `Option` into a `StrictResult`, using `err` if it is `None`.
+///
+/// ```
+/// use strict_result::ok_or_strict;
+///
+/// assert_eq!(ok_or_strict(Some(1), "missing"), Ok(1));
+/// assert_eq!(ok_or_strict(None::<i32>, "missing"), Err("missing"));
+/// ```
+pub fn ok_or_strict<A, B>(opt: Option<A>, err: B) -> StrictResult<A, B> {
+	opt.ok_or(err).strict()
+}
+
+/// Converts an `Option` into a `StrictResult`, calling `err` if it is `None`.
+///
+/// ```
+/// use strict_result::ok_or_else_strict;
+///
+/// let mut calls = 0;
+/// assert_eq!(ok_or_else_strict(Some(1), || { calls += 1; "missing" }), Ok(1));
+/// assert_eq!(ok_or_else_strict(None::<i32>, || { calls += 1; "missing" }), Err("missing"));
+/// assert_eq!(calls, 1);
+/// ```
+pub fn ok_or_else_strict<A, B>(opt: Option<A>, err: impl FnOnce() -> B) -> StrictResult<A, B> {
+	opt.ok_or_else(err).strict()
+}
+
+/// Calls `f` and converts its result into a `StrictResult`.
+///
+/// This is the same as `f().strict()`, which can read better when `f` is a large block.
+///
+/// ```
+/// use strict_result::{strict_from_fn, StrictResult};
+///
+/// let v = [1, 2];
+/// let r: StrictResult<i32, &str> = strict_from_fn(|| {
+///     let a = v.first().ok_or("empty")?;
+///     Ok(a * 10)
+/// });
+/// assert_eq!(r, Ok(10));
+///
+/// let r: StrictResult<i32, &str> = strict_from_fn(|| {
+///     let a = v.get(5).ok_or("too short")?;
+///     Ok(a * 10)
+/// });
+/// assert_eq!(r, Err("too short"));
+/// ```
+pub fn strict_from_fn<A, B>(f: impl FnOnce() -> Result<A, B>) -> StrictResult<A, B> {
+	f().strict()
+}
+
+/// Returns `Ok(ok())` if `cond` is `true`, otherwise `Err(err())`.
+///
+/// ```
+/// use strict_result::then_strict;
+///
+/// let (mut oks, mut errs) = (0, 0);
+/// assert_eq!(then_strict(true, || { oks += 1; 1 }, || { errs += 1; "bad" }), Ok(1));
+/// assert_eq!((oks, errs), (1, 0));
+/// assert_eq!(then_strict(false, || { oks += 1; 1 }, || { errs += 1; "bad" }), Err("bad"));
+/// assert_eq!((oks, errs), (1, 1));
+/// ```
+pub fn then_strict<A, B>(cond: bool, ok: impl FnOnce() -> A, err: impl FnOnce() -> B) -> StrictResult<A, B> {
+	if cond { Ok(ok()) } else { Err(err()) }.strict()
+}
+
+/// Combines three results into a result of a tuple, returning the first error encountered.
+///
+/// All results must have the same error type. See [`StrictResult::zip`] for two results.
+///
+/// ```
+/// use strict_result::{zip3_strict, Strict};
+///
+/// let parse = |s: &str| s.parse::<i32>().strict().map_err(|_| s.to_string());
+/// assert_eq!(zip3_strict(parse("1"), parse("2"), parse("3")), Ok((1, 2, 3)));
+/// assert_eq!(zip3_strict(parse("1"), parse("x"), parse("y")), Err("x".to_string()));
+/// ```
+pub fn zip3_strict<A, B, C, E>(
+	a: StrictResult<A, E>,
+	b: StrictResult<B, E>,
+	c: StrictResult<C, E>,
+) -> StrictResult<(A, B, C), E> {
+	a.zip(b).zip(c).map(|((a, b), c)| (a, b, c))
+}
+
+/// Sorts a slice of results, with all `Ok` values before all `Err` values, and each variant sorted
+/// by its inner value.
+///
+/// This is the same order as the `Ord` implementation, and the sort is stable.
+///
+/// ```
+/// use strict_result::{sort_strict_results, Strict};
+///
+/// let mut values = [Err("b").strict(), Ok(2).strict(), Err("a").strict(), Ok(1).strict()];
+/// sort_strict_results(&mut values);
+/// assert_eq!(values, [Ok(1).strict(), Ok(2).strict(), Err("a").strict(), Err("b").strict()]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn sort_strict_results<A: Ord, B: Ord>(slice: &mut [StrictResult<A, B>]) {
+	slice.sort();
+}
+
+/// Unwraps a `Result` or returns its error, without the implicit `.into()`.
+///
+/// `strict!(expr)` is an alternative spelling of `expr.strict()?`, which can read more clearly in
+/// long expressions. It works in functions returning both `Result` and `StrictResult`.
+///
+/// ```
+/// use strict_result::{strict, Strict, StrictResult};
+///
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// fn lenient(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     Ok(strict!(parse(s)) + 1)
+/// }
+///
+/// fn strict(s: &str) -> StrictResult<i32, std::num::ParseIntError> {
+///     Ok(strict!(parse(s)) + 1).strict()
+/// }
+///
+/// assert_eq!(lenient("1"), Ok(2));
+/// assert!(lenient("x").is_err());
+/// assert_eq!(strict("1"), Ok(2));
+/// assert!(strict("x").is_err());
+/// ```
+///
+/// Like `.strict()?`, it does not convert the error type:
+///
+/// ```compile_fail
+/// use strict_result::strict;
+///
+/// fn widen(s: &str) -> Result<i32, Box<dyn std::error::Error>> {
+///     Ok(strict!(s.parse::<i32>()))
+/// }
+/// ```
+#[cfg(not(feature = "stable"))]
+#[macro_export]
+macro_rules! strict {
+	($e:expr $(,)?) => {
+		$crate::Strict::strict($e)?
+	};
+}
+
+/// Unwraps a `Result` or returns its error, without the implicit `.into()` and without using `?`.
+///
+/// This works like the old `try!` macro, and is mainly useful under the `stable` feature, where
+/// `?` and `strict!` are not available on `StrictResult`. It works in functions returning both
+/// `Result` and `StrictResult`.
+///
+/// ```
+/// use strict_result::{strict_try, Strict, StrictResult};
+///
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// fn lenient(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     Ok(strict_try!(parse(s)) + 1)
+/// }
+///
+/// fn strict(s: &str) -> StrictResult<i32, std::num::ParseIntError> {
+///     Ok(strict_try!(parse(s)) + 1).strict()
+/// }
+///
+/// assert_eq!(lenient("1"), Ok(2));
+/// assert!(lenient("x").is_err());
+/// assert_eq!(strict("1"), Ok(2));
+/// assert!(strict("x").is_err());
+/// ```
+#[macro_export]
+macro_rules! strict_try {
+	($e:expr $(,)?) => {
+		match $crate::Strict::strict($e).loose() {
+			Ok(v) => v,
+			Err(e) => return $crate::__private::FromStrictErr::from_strict_err(e),
+		}
+	};
+}
+
+/// Asserts that a `StrictResult` or `Result` is `Ok`, and evaluates to the `Ok` value.
+///
+/// Otherwise, panics with the `Debug` representation of the error.
+///
+/// ```
+/// use strict_result::{assert_strict_ok, Strict};
+///
+/// assert_eq!(assert_strict_ok!(Ok::<i32, &str>(1).strict()), 1);
+///
+/// let payload = std::panic::catch_unwind(|| {
+///     assert_strict_ok!(Err::<i32, &str>("bad").strict());
+/// }).unwrap_err();
+/// assert_eq!(payload.downcast_ref::<String>().unwrap(), r#"assertion failed: expected `Ok`, got `Err("bad")`"#);
+/// ```
+#[macro_export]
+macro_rules! assert_strict_ok {
+	($e:expr $(,)?) => {
+		match $crate::Strict::strict($e).loose() {
+			Ok(v) => v,
+			Err(e) => panic!("assertion failed: expected `Ok`, got `Err({:?})`", e),
+		}
+	};
+}
+
+/// Asserts that a `StrictResult` or `Result` is `Err`, and evaluates to the `Err` value.
+///
+/// Otherwise, panics with the `Debug` representation of the value.
+///
+/// ```
+/// use strict_result::{assert_strict_err, Strict};
+///
+/// assert_eq!(assert_strict_err!(Err::<i32, &str>("bad").strict()), "bad");
+///
+/// let payload = std::panic::catch_unwind(|| {
+///     assert_strict_err!(Ok::<i32, &str>(1).strict());
+/// }).unwrap_err();
+/// assert_eq!(payload.downcast_ref::<String>().unwrap(), "assertion failed: expected `Err`, got `Ok(1)`");
+/// ```
+#[macro_export]
+macro_rules! assert_strict_err {
+	($e:expr $(,)?) => {
+		match $crate::Strict::strict($e).loose() {
+			Ok(v) => panic!("assertion failed: expected `Err`, got `Ok({:?})`", v),
+			Err(e) => e,
+		}
+	};
+}
+
 impl<A, B> StrictResult<A, B> {
 	/// Converts a `StrictResult` into a `Result`.
-	pub fn loose(self) -> Result<A, B> {
-		self.0
+	///
+	/// This is the same as [`into_result`](StrictResult::into_result).
+	pub const fn loose(self) -> Result<A, B> {
+		// Moving out of `self.0` directly is not allowed in a const fn, since the compiler can't
+		// tell that there is nothing left to drop.
+		let this = core::mem::ManuallyDrop::new(self);
+		// SAFETY: ManuallyDrop<StrictResult> is transparent over Result, and is never used again.
+		unsafe { core::ptr::read((&raw const this).cast::<Result<A, B>>()) }
+	}
+
+	/// Converts a `StrictResult` into a `Result`.
+	///
+	/// This is the same as [`loose`](StrictResult::loose).
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// for x in [Ok::<i32, &str>(1).strict(), Err("bad").strict()] {
+	///     assert_eq!(x.into_result(), x.loose());
+	/// }
+	/// ```
+	pub const fn into_result(self) -> Result<A, B> {
+		self.loose()
+	}
+
+	/// Returns `true` if the result is `Ok`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert!(Ok::<i32, ()>(1).strict().is_ok());
+	/// assert!(!Err::<i32, ()>(()).strict().is_ok());
+	/// ```
+	pub const fn is_ok(&self) -> bool {
+		self.0.is_ok()
+	}
+
+	/// Returns `true` if the result is `Ok` and the value inside of it matches a predicate.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert!(Ok::<String, ()>("a".into()).strict().is_ok_and(|v: String| v == "a"));
+	/// assert!(!Ok::<String, ()>("b".into()).strict().is_ok_and(|v: String| v == "a"));
+	/// assert!(!Err::<String, ()>(()).strict().is_ok_and(|_| true));
+	/// ```
+	pub fn is_ok_and(self, f: impl FnOnce(A) -> bool) -> bool {
+		self.0.is_ok_and(f)
+	}
+
+	/// Returns `true` if the result is `Err`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert!(Err::<i32, ()>(()).strict().is_err());
+	/// assert!(!Ok::<i32, ()>(1).strict().is_err());
+	/// ```
+	pub const fn is_err(&self) -> bool {
+		self.0.is_err()
+	}
+
+	/// Returns `true` if the result is `Err` and the value inside of it matches a predicate.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert!(Err::<(), String>("a".into()).strict().is_err_and(|e: String| e == "a"));
+	/// assert!(!Err::<(), String>("b".into()).strict().is_err_and(|e: String| e == "a"));
+	/// assert!(!Ok::<(), String>(()).strict().is_err_and(|_| true));
+	/// ```
+	pub fn is_err_and(self, f: impl FnOnce(B) -> bool) -> bool {
+		self.0.is_err_and(f)
+	}
+
+	/// Converts from `&StrictResult<A, B>` to `StrictResult<&A, &B>`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let x = Ok::<String, ()>("hello".into()).strict();
+	/// assert_eq!(x.as_ref().map(|v| v.len()), Ok(5));
+	/// assert_eq!(x, Ok("hello".into()));
+	/// ```
+	pub const fn as_ref(&self) -> StrictResult<&A, &B> {
+		StrictResult(self.0.as_ref())
+	}
+
+	/// Converts from `&mut StrictResult<A, B>` to `StrictResult<&mut A, &mut B>`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut x = Ok::<i32, i32>(2).strict();
+	/// if let Ok(v) = x.as_mut().loose() {
+	///     *v = 42;
+	/// }
+	/// assert_eq!(x, Ok(42));
+	///
+	/// let mut y = Err::<i32, i32>(2).strict();
+	/// assert!(y.as_mut().is_err());
+	/// ```
+	pub const fn as_mut(&mut self) -> StrictResult<&mut A, &mut B> {
+		StrictResult(self.0.as_mut())
+	}
+
+	/// Converts from `&StrictResult<A, B>` to `StrictResult<&A::Target, &B>`.
+	///
+	/// ```
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// let x = Ok::<String, u32>("hello".into()).strict();
+	/// let y: StrictResult<&str, &u32> = x.as_deref();
+	/// assert_eq!(y, Ok("hello"));
+	///
+	/// let x = Err::<String, u32>(42).strict();
+	/// assert_eq!(x.as_deref(), Err(&42));
+	/// ```
+	pub fn as_deref(&self) -> StrictResult<&A::Target, &B> where A: core::ops::Deref {
+		self.0.as_deref().strict()
+	}
+
+	/// Converts from `&mut StrictResult<A, B>` to `StrictResult<&mut A::Target, &mut B>`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut x = Ok::<String, u32>("hello".into()).strict();
+	/// if let Ok(s) = x.as_deref_mut().loose() {
+	///     s.make_ascii_uppercase();
+	/// }
+	/// assert_eq!(x, Ok("HELLO".into()));
+	/// ```
+	pub fn as_deref_mut(&mut self) -> StrictResult<&mut A::Target, &mut B> where A: core::ops::DerefMut {
+		self.0.as_deref_mut().strict()
 	}
+
+	/// Borrows the inner `Result`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// fn takes_result(r: &Result<i32, ()>) -> bool {
+	///     r.is_ok()
+	/// }
+	/// assert!(takes_result(Ok(1).strict().as_loose()));
+	/// ```
+	pub const fn as_loose(&self) -> &Result<A, B> {
+		&self.0
+	}
+
+	/// Mutably borrows the inner `Result`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut x = Ok::<i32, &str>(1).strict();
+	/// *x.as_loose_mut() = Err("replaced");
+	/// assert_eq!(x, Err("replaced"));
+	/// ```
+	pub const fn as_loose_mut(&mut self) -> &mut Result<A, B> {
+		&mut self.0
+	}
+
+	/// Replaces an `Err` with `Ok(f())`, and returns a mutable reference to the `Ok` value.
+	///
+	/// If `self` is already `Ok`, `f` is not called. This mirrors [`Option::get_or_insert_with`].
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut x = Err::<i32, &str>("bad").strict();
+	/// *x.get_or_insert_with(|| 1) += 1;
+	/// assert_eq!(x, Ok(2));
+	///
+	/// let mut x = Ok::<i32, &str>(5).strict();
+	/// *x.get_or_insert_with(|| unreachable!()) += 1;
+	/// assert_eq!(x, Ok(6));
+	/// ```
+	pub fn get_or_insert_with(&mut self, f: impl FnOnce() -> A) -> &mut A {
+		if self.0.is_err() {
+			self.0 = Ok(f());
+		}
+		match &mut self.0 {
+			Ok(v) => v,
+			Err(_) => unreachable!(),
+		}
+	}
+
+	/// Replaces an `Err` with `Ok(value)`, and returns a mutable reference to the `Ok` value.
+	///
+	/// If `self` is already `Ok`, `value` is dropped. See
+	/// [`get_or_insert_with`](StrictResult::get_or_insert_with) for a lazy version.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut x = Err::<i32, &str>("bad").strict();
+	/// *x.or_insert(1) += 1;
+	/// assert_eq!(x, Ok(2));
+	///
+	/// let mut x = Ok::<i32, &str>(5).strict();
+	/// *x.or_insert(1) += 1;
+	/// assert_eq!(x, Ok(6));
+	/// ```
+	pub fn or_insert(&mut self, value: A) -> &mut A {
+		self.get_or_insert_with(|| value)
+	}
+
+	/// Maps the `Ok` value with `f`, leaving an `Err` untouched.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut calls = 0;
+	/// assert_eq!(Ok::<i32, ()>(2).strict().map(|v| { calls += 1; v * 2 }), Ok(4));
+	/// assert_eq!(Err::<i32, ()>(()).strict().map(|v| { calls += 1; v * 2 }), Err(()));
+	/// assert_eq!(calls, 1);
+	/// ```
+	pub fn map<U>(self, f: impl FnOnce(A) -> U) -> StrictResult<U, B> {
+		self.0.map(f).strict()
+	}
+
+	/// Maps the `Err` value with `f`, leaving an `Ok` untouched.
+	///
+	/// Only `f` is applied to the error; there is no implicit `.into()` afterwards.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut calls = 0;
+	/// assert_eq!(Err::<(), i32>(2).strict().map_err(|e| { calls += 1; e * 2 }), Err(4));
+	/// assert_eq!(Ok::<(), i32>(()).strict().map_err(|e| { calls += 1; e * 2 }), Ok(()));
+	/// assert_eq!(calls, 1);
+	/// ```
+	pub fn map_err<F>(self, f: impl FnOnce(B) -> F) -> StrictResult<A, F> {
+		self.0.map_err(f).strict()
+	}
+
+	/// Maps the `Err` value with `f`, and converts into a `Result`.
+	///
+	/// This is the same as `.map_err(f).loose()`, for use where strictness is no longer needed.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let x: Result<(), String> = Err::<(), i32>(2).strict().map_err_loose(|e| e.to_string());
+	/// assert_eq!(x, Err("2".to_string()));
+	/// let x: Result<i32, String> = Ok::<i32, i32>(1).strict().map_err_loose(|e| e.to_string());
+	/// assert_eq!(x, Ok(1));
+	/// ```
+	pub fn map_err_loose<F>(self, f: impl FnOnce(B) -> F) -> Result<A, F> {
+		self.0.map_err(f)
+	}
+
+	/// Maps the `Err` value into a borrowed or owned string message.
+	///
+	/// This is the same as [`map_err`](StrictResult::map_err) with the error type fixed to
+	/// `Cow<str>`, which allows static messages without allocating.
+	///
+	/// ```
+	/// use std::borrow::Cow;
+	/// use strict_result::Strict;
+	///
+	/// let describe = |e: i32| if e == 0 { Cow::Borrowed("zero") } else { Cow::Owned(format!("error {e}")) };
+	///
+	/// let x = Err::<(), i32>(0).strict().map_err_borrowed(describe);
+	/// assert!(matches!(x.unwrap_err(), Cow::Borrowed("zero")));
+	/// let x = Err::<(), i32>(2).strict().map_err_borrowed(describe);
+	/// assert!(matches!(x.unwrap_err(), Cow::Owned(s) if s == "error 2"));
+	/// assert_eq!(Ok::<i32, i32>(1).strict().map_err_borrowed(describe), Ok(1));
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn map_err_borrowed<'a>(self, f: impl FnOnce(B) -> alloc::borrow::Cow<'a, str>) -> StrictResult<A, alloc::borrow::Cow<'a, str>> {
+		self.map_err(f)
+	}
+
+	/// Maps the `Ok` value with `ok`, or the `Err` value with `err`.
+	///
+	/// This is the same as `.map(ok).map_err(err)`. As with [`map_err`](StrictResult::map_err),
+	/// there is no implicit `.into()` on the error.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let (mut oks, mut errs) = (0, 0);
+	/// let mut f = |r: strict_result::StrictResult<i32, &str>| {
+	///     r.map_both(|v| { oks += 1; v * 2 }, |e| { errs += 1; e.len() })
+	/// };
+	/// assert_eq!(f(Ok(2).strict()), Ok(4));
+	/// assert_eq!(f(Err("bad").strict()), Err(3));
+	/// assert_eq!(f(Ok(3).strict()), Ok(6));
+	/// assert_eq!((oks, errs), (2, 1));
+	/// ```
+	pub fn map_both<U, F>(self, ok: impl FnOnce(A) -> U, err: impl FnOnce(B) -> F) -> StrictResult<U, F> {
+		match self.0 {
+			Ok(v) => Ok(ok(v)),
+			Err(e) => Err(err(e)),
+		}.strict()
+	}
+
+	/// Returns `default` if `Err`, or applies `f` to the `Ok` value.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, ()>(2).strict().map_or(0, |v| v * 2), 4);
+	/// assert_eq!(Err::<i32, ()>(()).strict().map_or(0, |v| v * 2), 0);
+	/// ```
+	pub fn map_or<U>(self, default: U, f: impl FnOnce(A) -> U) -> U {
+		self.0.map_or(default, f)
+	}
+
+	/// Applies `default` to the `Err` value or `f` to the `Ok` value.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut calls = 0;
+	/// assert_eq!(Ok::<i32, i32>(2).strict().map_or_else(|e| e - 1, |v| { calls += 1; v * 2 }), 4);
+	/// assert_eq!(Err::<i32, i32>(2).strict().map_or_else(|e| e - 1, |v| { calls += 1; v * 2 }), 1);
+	/// assert_eq!(calls, 1);
+	/// ```
+	pub fn map_or_else<U>(self, default: impl FnOnce(B) -> U, f: impl FnOnce(A) -> U) -> U {
+		self.0.map_or_else(default, f)
+	}
+
+	/// Returns `U::default()` if `Err`, or applies `f` to the `Ok` value.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let digits = |s: &str| s.chars().filter(char::is_ascii_digit).collect::<String>();
+	/// assert_eq!(Ok::<&str, ()>("a1b2").strict().map_or_default(digits), "12");
+	/// assert_eq!(Err::<&str, ()>(()).strict().map_or_default(digits), "");
+	/// ```
+	pub fn map_or_default<U: Default>(self, f: impl FnOnce(A) -> U) -> U {
+		match self.0 {
+			Ok(v) => f(v),
+			Err(_) => U::default(),
+		}
+	}
+
+	/// Returns `other` if `Ok`, otherwise returns the `Err` value of `self`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, &str>(1).strict().and(Ok::<&str, _>("two").strict()), Ok("two"));
+	/// assert_eq!(Ok::<i32, &str>(1).strict().and(Err::<(), _>("late").strict()), Err("late"));
+	/// assert_eq!(Err::<i32, &str>("early").strict().and(Err::<(), _>("late").strict()), Err("early"));
+	/// ```
+	pub fn and<U>(self, other: StrictResult<U, B>) -> StrictResult<U, B> {
+		self.0.and(other.0).strict()
+	}
+
+	/// Calls `f` on the `Ok` value, otherwise returns the `Err` value of `self`.
+	///
+	/// Since `f` returns a `StrictResult` with the same error type, no conversion takes place.
+	///
+	/// ```
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// fn halve(v: i32) -> StrictResult<i32, String> {
+	///     if v % 2 == 0 { Ok(v / 2) } else { Err(format!("{v} is odd")) }.strict()
+	/// }
+	///
+	/// assert_eq!(Ok(8).strict().and_then(halve).and_then(halve).and_then(halve), Ok(1));
+	///
+	/// let mut calls = 0;
+	/// let mut counted = |v| { calls += 1; halve(v) };
+	/// assert_eq!(Ok(6).strict().and_then(&mut counted).and_then(&mut counted).and_then(&mut counted), Err("3 is odd".into()));
+	/// assert_eq!(calls, 2);
+	/// ```
+	pub fn and_then<U>(self, f: impl FnOnce(A) -> StrictResult<U, B>) -> StrictResult<U, B> {
+		self.0.and_then(|v| f(v).0).strict()
+	}
+
+	/// Applies a fallible mapping to the `Ok` value.
+	///
+	/// This is the same as [`and_then`](StrictResult::and_then), under a name that reads as a
+	/// transformation. `f` must return the same error type, so no conversion takes place.
+	///
+	/// ```
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// let parse = |s: &str| s.parse::<u32>().strict();
+	/// let non_zero = |v: u32| std::num::NonZeroU32::new(v).ok_or("zero").strict();
+	///
+	/// let r: StrictResult<_, &str> = Ok("5").strict().try_map(|s| parse(s).map_err(|_| "nan")).try_map(non_zero);
+	/// assert_eq!(r.map(|v| v.get()), Ok(5));
+	/// let r: StrictResult<_, &str> = Ok("0").strict().try_map(|s| parse(s).map_err(|_| "nan")).try_map(non_zero);
+	/// assert_eq!(r, Err("zero"));
+	/// ```
+	pub fn try_map<U>(self, f: impl FnOnce(A) -> StrictResult<U, B>) -> StrictResult<U, B> {
+		self.and_then(f)
+	}
+
+	/// Calls `f` on the `Ok` value, otherwise returns the `Err` value of `self`, as a `Result`.
+	///
+	/// This allows a strict step to be followed by a lenient one, for example at the end of a
+	/// chain that is handed to a caller expecting a `Result`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+	///     s.parse::<i32>().strict().and_then_loose(|v| i32::from_str_radix(&v.to_string(), 16))
+	/// }
+	/// assert_eq!(parse("10"), Ok(16));
+	/// assert!(parse("x").is_err());
+	/// ```
+	pub fn and_then_loose<U>(self, f: impl FnOnce(A) -> Result<U, B>) -> Result<U, B> {
+		self.0.and_then(f)
+	}
+
+	/// Returns `other` if `Err`, otherwise returns the `Ok` value of `self`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Err::<i32, &str>("bad").strict().or(Ok::<_, ()>(2).strict()), Ok(2));
+	/// assert_eq!(Ok::<i32, &str>(1).strict().or(Ok::<_, ()>(2).strict()), Ok(1));
+	/// assert_eq!(Err::<i32, &str>("bad").strict().or(Err::<_, ()>(()).strict()), Err(()));
+	/// ```
+	pub fn or<F>(self, other: StrictResult<A, F>) -> StrictResult<A, F> {
+		self.0.or(other.0).strict()
+	}
+
+	/// Calls `f` on the `Err` value, otherwise returns the `Ok` value of `self`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let retry = |e: i32| if e < 3 { Ok(e * 10) } else { Err(e) }.strict();
+	/// assert_eq!(Err::<i32, i32>(1).strict().or_else(retry), Ok(10));
+	/// assert_eq!(Err::<i32, i32>(4).strict().or_else(retry), Err(4));
+	/// assert_eq!(Ok::<i32, i32>(7).strict().or_else(retry), Ok(7));
+	/// ```
+	pub fn or_else<F>(self, f: impl FnOnce(B) -> StrictResult<A, F>) -> StrictResult<A, F> {
+		self.0.or_else(|e| f(e).0).strict()
+	}
+
+	/// Calls `f` on the `Err` value, otherwise returns the `Ok` value of `self`, as a `Result`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let fallback = |e: &str| if e == "missing" { Ok(0) } else { Err(e.len()) };
+	/// let x: Result<i32, usize> = Err("missing").strict().or_else_loose(fallback);
+	/// assert_eq!(x, Ok(0));
+	/// assert_eq!(Err("bad").strict().or_else_loose(fallback), Err(3));
+	/// assert_eq!(Ok(7).strict().or_else_loose(fallback), Ok(7));
+	/// ```
+	pub fn or_else_loose<F>(self, f: impl FnOnce(B) -> Result<A, F>) -> Result<A, F> {
+		self.0.or_else(f)
+	}
+
+	/// Keeps an `Ok` value only if `predicate` returns `true`, otherwise returns `Err(err)`.
+	///
+	/// An `Err` is passed through unchanged, without calling `predicate`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let positive = |v: &i32| *v > 0;
+	/// assert_eq!(Ok::<i32, &str>(1).strict().filter(positive, "not positive"), Ok(1));
+	/// assert_eq!(Ok::<i32, &str>(-1).strict().filter(positive, "not positive"), Err("not positive"));
+	/// assert_eq!(Err::<i32, &str>("bad").strict().filter(positive, "not positive"), Err("bad"));
+	/// ```
+	pub fn filter(self, predicate: impl FnOnce(&A) -> bool, err: B) -> StrictResult<A, B> {
+		self.filter_or_else(predicate, |_| err)
+	}
+
+	/// Keeps an `Ok` value only if `predicate` returns `true`, otherwise calls `err` on it.
+	///
+	/// An `Err` is passed through unchanged, without calling either function.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut calls = 0;
+	/// let mut check = |r: strict_result::StrictResult<i32, String>| {
+	///     r.filter_or_else(|v| *v > 0, |v| { calls += 1; format!("{v} is not positive") })
+	/// };
+	/// assert_eq!(check(Ok(1).strict()), Ok(1));
+	/// assert_eq!(check(Ok(-1).strict()), Err("-1 is not positive".into()));
+	/// assert_eq!(check(Err("bad".into()).strict()), Err("bad".into()));
+	/// assert_eq!(calls, 1);
+	/// ```
+	pub fn filter_or_else(self, predicate: impl FnOnce(&A) -> bool, err: impl FnOnce(A) -> B) -> StrictResult<A, B> {
+		match self.0 {
+			Ok(v) if predicate(&v) => Ok(v),
+			Ok(v) => Err(err(v)),
+			Err(e) => Err(e),
+		}.strict()
+	}
+
+	/// Keeps an `Ok` value only if `is_valid` returns `true`, otherwise builds an error from it
+	/// with `make_err`.
+	///
+	/// This is like [`filter_or_else`](StrictResult::filter_or_else), but `make_err` only
+	/// borrows the value.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let even = |r: strict_result::StrictResult<i32, String>| {
+	///     r.checked(|v| v % 2 == 0, |v| format!("{v} is odd"))
+	/// };
+	/// assert_eq!(even(Ok(2).strict()), Ok(2));
+	/// assert_eq!(even(Ok(3).strict()), Err("3 is odd".into()));
+	/// assert_eq!(even(Err("bad".into()).strict()), Err("bad".into()));
+	/// ```
+	pub fn checked(self, is_valid: impl FnOnce(&A) -> bool, make_err: impl FnOnce(&A) -> B) -> StrictResult<A, B> {
+		self.filter_or_else(is_valid, |v| make_err(&v))
+	}
+
+	/// Returns the `Ok` value, panicking if it is an `Err`.
+	///
+	/// The panic message is the same as for [`Result::unwrap`].
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, ()>(1).strict().unwrap(), 1);
+	/// ```
+	///
+	/// ```should_panic
+	/// use strict_result::Strict;
+	///
+	/// Err::<i32, &str>("emergency failure").strict().unwrap();
+	/// ```
+	#[track_caller]
+	pub fn unwrap(self) -> A where B: core::fmt::Debug {
+		self.0.unwrap()
+	}
+
+	/// Returns the `Ok` value, panicking with `msg` if it is an `Err`.
+	///
+	/// The panic message is the same as for [`Result::expect`].
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, ()>(1).strict().expect("should be ok"), 1);
+	/// ```
+	///
+	/// ```should_panic
+	/// use strict_result::Strict;
+	///
+	/// Err::<i32, &str>("emergency failure").strict().expect("Testing expect");
+	/// ```
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let payload = std::panic::catch_unwind(|| {
+	///     Err::<i32, &str>("emergency failure").strict().expect("Testing expect")
+	/// }).unwrap_err();
+	/// assert_eq!(payload.downcast_ref::<String>().unwrap(), r#"Testing expect: "emergency failure""#);
+	/// ```
+	#[track_caller]
+	pub fn expect(self, msg: &str) -> A where B: core::fmt::Debug {
+		self.0.expect(msg)
+	}
+
+	/// Returns the `Ok` value, panicking with `msg` if it is an `Err`.
+	///
+	/// This is the same as [`expect`](StrictResult::expect).
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, ()>(1).strict().expect_ok("should be ok"), 1);
+	/// ```
+	///
+	/// ```should_panic
+	/// use strict_result::Strict;
+	///
+	/// Err::<i32, &str>("emergency failure").strict().expect_ok("Testing expect_ok");
+	/// ```
+	#[track_caller]
+	pub fn expect_ok(self, msg: &str) -> A where B: core::fmt::Debug {
+		self.expect(msg)
+	}
+
+	/// Returns the `Ok` value, panicking with the message returned by `f` if it is an `Err`.
+	///
+	/// Unlike [`expect`](StrictResult::expect), this does not require the error to be `Debug`,
+	/// and allows building a more descriptive message from it.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// struct Missing(&'static str);
+	///
+	/// assert_eq!(Ok::<i32, Missing>(1).strict().expect_with(|_| unreachable!()), 1);
+	///
+	/// let payload = std::panic::catch_unwind(|| {
+	///     Err::<i32, _>(Missing("config.toml")).strict().expect_with(|e| format!("{} not found", e.0))
+	/// }).unwrap_err();
+	/// assert_eq!(payload.downcast_ref::<String>().unwrap(), "config.toml not found");
+	/// ```
+	#[cfg(feature = "alloc")]
+	#[track_caller]
+	pub fn expect_with(self, f: impl FnOnce(&B) -> alloc::string::String) -> A {
+		match self.0 {
+			Ok(v) => v,
+			Err(e) => panic!("{}", f(&e)),
+		}
+	}
+
+	/// Returns the `Err` value, panicking if it is an `Ok`.
+	///
+	/// The panic message is the same as for [`Result::unwrap_err`].
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Err::<(), i32>(1).strict().unwrap_err(), 1);
+	/// ```
+	///
+	/// ```should_panic
+	/// use strict_result::Strict;
+	///
+	/// Ok::<i32, ()>(2).strict().unwrap_err();
+	/// ```
+	#[track_caller]
+	pub fn unwrap_err(self) -> B where A: core::fmt::Debug {
+		self.0.unwrap_err()
+	}
+
+	/// Returns the `Err` value, panicking with `msg` if it is an `Ok`.
+	///
+	/// The panic message is the same as for [`Result::expect_err`].
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Err::<(), i32>(1).strict().expect_err("should be err"), 1);
+	/// ```
+	///
+	/// ```should_panic
+	/// use strict_result::Strict;
+	///
+	/// Ok::<i32, ()>(2).strict().expect_err("Testing expect_err");
+	/// ```
+	#[track_caller]
+	pub fn expect_err(self, msg: &str) -> B where A: core::fmt::Debug {
+		self.0.expect_err(msg)
+	}
+
+	/// Returns the `Ok` value, or `default` if it is an `Err`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, ()>(1).strict().unwrap_or(2), 1);
+	/// assert_eq!(Err::<i32, ()>(()).strict().unwrap_or(2), 2);
+	/// ```
+	pub fn unwrap_or(self, default: A) -> A {
+		self.0.unwrap_or(default)
+	}
+
+	/// Returns the `Ok` value, or computes it from the `Err` value with `f`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<usize, &str>(1).strict().unwrap_or_else(str::len), 1);
+	/// assert_eq!(Err::<usize, &str>("four").strict().unwrap_or_else(str::len), 4);
+	/// ```
+	pub fn unwrap_or_else(self, f: impl FnOnce(B) -> A) -> A {
+		self.0.unwrap_or_else(f)
+	}
+
+	/// Returns the `Ok` value, or `A::default()` if it is an `Err`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, ()>(1).strict().unwrap_or_default(), 1);
+	/// assert_eq!(Err::<i32, ()>(()).strict().unwrap_or_default(), 0);
+	/// ```
+	pub fn unwrap_or_default(self) -> A where A: Default {
+		self.0.unwrap_or_default()
+	}
+
+	/// Returns the `Err` value, or `B::default()` if it is an `Ok`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Err::<(), Vec<&str>>(vec!["bad"]).strict().unwrap_err_or_default(), ["bad"]);
+	/// assert_eq!(Ok::<(), Vec<&str>>(()).strict().unwrap_err_or_default(), Vec::<&str>::new());
+	/// ```
+	pub fn unwrap_err_or_default(self) -> B where B: Default {
+		self.0.err().unwrap_or_default()
+	}
+
+	/// Returns the `Ok` value, without checking that it is not an `Err`.
+	///
+	/// # Safety
+	///
+	/// Calling this on an `Err` is *[undefined behavior]*.
+	///
+	/// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let x = Ok::<i32, ()>(1).strict();
+	/// if x.is_ok() {
+	///     assert_eq!(unsafe { x.unwrap_unchecked() }, 1);
+	/// }
+	/// ```
+	#[track_caller]
+	pub unsafe fn unwrap_unchecked(self) -> A {
+		// SAFETY: the caller guarantees that this is an `Ok`.
+		unsafe { self.0.unwrap_unchecked() }
+	}
+
+	/// Returns the `Err` value, without checking that it is not an `Ok`.
+	///
+	/// # Safety
+	///
+	/// Calling this on an `Ok` is *[undefined behavior]*.
+	///
+	/// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let x = Err::<(), i32>(1).strict();
+	/// if x.is_err() {
+	///     assert_eq!(unsafe { x.unwrap_err_unchecked() }, 1);
+	/// }
+	/// ```
+	#[track_caller]
+	pub unsafe fn unwrap_err_unchecked(self) -> B {
+		// SAFETY: the caller guarantees that this is an `Err`.
+		unsafe { self.0.unwrap_err_unchecked() }
+	}
+
+	/// Converts into an `Option` of the `Ok` value, discarding any error.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, &str>(1).strict().ok(), Some(1));
+	/// assert_eq!(Err::<i32, &str>("bad").strict().ok(), None);
+	/// ```
+	pub fn ok(self) -> Option<A> {
+		self.0.ok()
+	}
+
+	/// Converts into an `Option` of the `Err` value, discarding any success.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, &str>(1).strict().err(), None);
+	/// assert_eq!(Err::<i32, &str>("bad").strict().err(), Some("bad"));
+	/// ```
+	pub fn err(self) -> Option<B> {
+		self.0.err()
+	}
+
+	/// Converts into a pair of `Option`s, exactly one of which is `Some`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, &str>(1).strict().split(), (Some(1), None));
+	/// assert_eq!(Err::<i32, &str>("bad").strict().split(), (None, Some("bad")));
+	/// ```
+	pub fn split(self) -> (Option<A>, Option<B>) {
+		match self.0 {
+			Ok(v) => (Some(v), None),
+			Err(e) => (None, Some(e)),
+		}
+	}
+
+	/// Exchanges the `Ok` and `Err` values.
+	///
+	/// This allows `?` to short-circuit on success instead of failure, for example when trying
+	/// several alternatives.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, &str>(1).strict().swap(), Err(1));
+	/// assert_eq!(Err::<i32, &str>("bad").strict().swap(), Ok("bad"));
+	/// ```
+	///
+	#[cfg_attr(feature = "stable", doc = "```ignore")]
+	#[cfg_attr(not(feature = "stable"), doc = "```")]
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// fn first_number(v: &[&str]) -> StrictResult<(), i32> {
+	///     for s in v {
+	///         s.parse::<i32>().strict().swap()?;
+	///     }
+	///     Ok(()).strict()
+	/// }
+	/// assert_eq!(first_number(&["a", "2", "3"]), Err(2));
+	/// assert_eq!(first_number(&["a", "b"]), Ok(()));
+	/// ```
+	pub fn swap(self) -> StrictResult<B, A> {
+		match self.0 {
+			Ok(v) => Err(v),
+			Err(e) => Ok(e),
+		}.strict()
+	}
+
+	/// Calls `f` with a reference to the `Ok` value, if any, and returns `self` unchanged.
+	///
+	/// ```
+	/// use std::cell::Cell;
+	/// use strict_result::Strict;
+	///
+	/// let seen = Cell::new(0);
+	/// let x = Ok::<i32, i32>(4).strict().inspect(|v| seen.set(*v));
+	/// assert_eq!((x, seen.get()), (Ok(4).strict(), 4));
+	///
+	/// let x = Err::<i32, i32>(5).strict().inspect(|v| seen.set(*v));
+	/// assert_eq!((x, seen.get()), (Err(5).strict(), 4));
+	/// ```
+	pub fn inspect(self, f: impl FnOnce(&A)) -> Self {
+		self.0.inspect(f).strict()
+	}
+
+	/// Calls `f` with a reference to the `Err` value, if any, and returns `self` unchanged.
+	///
+	/// ```
+	/// use std::cell::Cell;
+	/// use strict_result::Strict;
+	///
+	/// let seen = Cell::new(0);
+	/// let x = Err::<i32, i32>(4).strict().inspect_err(|e| seen.set(*e));
+	/// assert_eq!((x, seen.get()), (Err(4).strict(), 4));
+	///
+	/// let x = Ok::<i32, i32>(5).strict().inspect_err(|e| seen.set(*e));
+	/// assert_eq!((x, seen.get()), (Ok(5).strict(), 4));
+	/// ```
+	pub fn inspect_err(self, f: impl FnOnce(&B)) -> Self {
+		self.0.inspect_err(f).strict()
+	}
+
+	/// Calls `f` with a reference to the inner `Result`, and returns `self` unchanged.
+	///
+	/// ```
+	/// use std::cell::Cell;
+	/// use strict_result::Strict;
+	///
+	/// let seen = Cell::new(None);
+	/// let x = Ok::<i32, i32>(1).strict().inspect_loose(|r| seen.set(Some(*r)));
+	/// assert_eq!((x, seen.get()), (Ok(1).strict(), Some(Ok(1))));
+	/// let x = Err::<i32, i32>(2).strict().inspect_loose(|r| seen.set(Some(*r)));
+	/// assert_eq!((x, seen.get()), (Err(2).strict(), Some(Err(2))));
+	/// ```
+	pub fn inspect_loose(self, f: impl FnOnce(&Result<A, B>)) -> Self {
+		f(&self.0);
+		self
+	}
+
+	/// Calls `f` with a `Result` of references to either value, and returns `self` unchanged.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut log = vec![];
+	/// let x = Ok::<i32, &str>(1).strict().inspect_both(|r| log.push(format!("{r:?}")));
+	/// assert_eq!(x, Ok(1));
+	/// let x = Err::<i32, &str>("bad").strict().inspect_both(|r| log.push(format!("{r:?}")));
+	/// assert_eq!(x, Err("bad"));
+	/// assert_eq!(log, ["Ok(1)", r#"Err("bad")"#]);
+	/// ```
+	pub fn inspect_both(self, f: impl FnOnce(Result<&A, &B>)) -> Self {
+		f(self.0.as_ref());
+		self
+	}
+
+	/// Calls `f` with a reference to the `Ok` value, if any, and returns `self` unchanged.
+	///
+	/// This is the same as [`inspect`](StrictResult::inspect).
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut log = vec![];
+	/// let x = Ok::<i32, &str>(1).strict().tap(|v| log.push(*v));
+	/// assert_eq!(x, Ok(1));
+	/// let x = Err::<i32, &str>("bad").strict().tap(|v| log.push(*v));
+	/// assert_eq!(x, Err("bad"));
+	/// assert_eq!(log, [1]);
+	/// ```
+	pub fn tap(self, f: impl FnOnce(&A)) -> Self {
+		self.inspect(f)
+	}
+
+	/// Calls `f` with a reference to the inner `Result`, and converts into a `Result`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut log = vec![];
+	/// let x: Result<i32, &str> = Ok(1).strict().tap_loose(|r| log.push(format!("{r:?}")));
+	/// assert_eq!(x, Ok(1));
+	/// let x: Result<i32, &str> = Err("bad").strict().tap_loose(|r| log.push(format!("{r:?}")));
+	/// assert_eq!(x, Err("bad"));
+	/// assert_eq!(log, ["Ok(1)", r#"Err("bad")"#]);
+	/// ```
+	pub fn tap_loose(self, f: impl FnOnce(&Result<A, B>)) -> Result<A, B> {
+		self.inspect_loose(f).loose()
+	}
+
+	/// Converts the error type with `From`, like `?` does on a `Result`.
+	///
+	/// This makes the conversion a visible, named step, rather than an implicit one.
+	///
+	/// ```
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// #[derive(Debug, PartialEq)]
+	/// struct AError;
+	/// #[derive(Debug, PartialEq)]
+	/// enum ABError { A(AError) }
+	///
+	/// impl From<AError> for ABError {
+	///     fn from(a: AError) -> ABError { ABError::A(a) }
+	/// }
+	///
+	/// let x: StrictResult<(), ABError> = Err(AError).strict().widen();
+	/// assert_eq!(x, Err(ABError::A(AError)));
+	/// ```
+	///
+	/// Combined with `?`, this allows exactly the conversions that are spelled out:
+	///
+	#[cfg_attr(feature = "stable", doc = "```ignore")]
+	#[cfg_attr(not(feature = "stable"), doc = "```")]
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// # #[derive(Debug, PartialEq)]
+	/// # struct AError;
+	/// # #[derive(Debug, PartialEq)]
+	/// # struct BError;
+	/// # #[derive(Debug, PartialEq)]
+	/// # enum ABError { A(AError), B(BError) }
+	/// #
+	/// # impl From<AError> for ABError {
+	/// #     fn from(a: AError) -> ABError { ABError::A(a) }
+	/// # }
+	/// # impl From<BError> for ABError {
+	/// #     fn from(b: BError) -> ABError { ABError::B(b) }
+	/// # }
+	/// #
+	/// fn a(ok: bool) -> Result<i32, AError> { if ok { Ok(1) } else { Err(AError) } }
+	/// fn b(ok: bool) -> Result<i32, BError> { if ok { Ok(2) } else { Err(BError) } }
+	///
+	/// fn both(x: bool, y: bool) -> StrictResult<i32, ABError> {
+	///     let x = a(x).strict().widen()?;
+	///     let y = b(y).strict().widen()?;
+	///     Ok(x + y).strict()
+	/// }
+	///
+	/// assert_eq!(both(true, true), Ok(3));
+	/// assert_eq!(both(false, true), Err(ABError::A(AError)));
+	/// assert_eq!(both(true, false), Err(ABError::B(BError)));
+	/// ```
+	pub fn widen<B2: From<B>>(self) -> StrictResult<A, B2> {
+		self.map_err(B2::from)
+	}
+
+	/// Converts the error type with `From`, and converts into a `Result`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// # #[derive(Debug, PartialEq)]
+	/// # struct AError;
+	/// # #[derive(Debug, PartialEq)]
+	/// # enum ABError { A(AError) }
+	/// #
+	/// # impl From<AError> for ABError {
+	/// #     fn from(a: AError) -> ABError { ABError::A(a) }
+	/// # }
+	/// #
+	/// let x: Result<(), ABError> = Err(AError).strict().widen_loose();
+	/// assert_eq!(x, Err(ABError::A(AError)));
+	/// ```
+	pub fn widen_loose<B2: From<B>>(self) -> Result<A, B2> {
+		self.widen().loose()
+	}
+
+	/// Converts both the success and error types with `From`, and converts into a `Result`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// # #[derive(Debug, PartialEq)]
+	/// # struct AError;
+	/// # #[derive(Debug, PartialEq)]
+	/// # enum ABError { A(AError) }
+	/// #
+	/// # impl From<AError> for ABError {
+	/// #     fn from(a: AError) -> ABError { ABError::A(a) }
+	/// # }
+	/// #
+	/// let x: Result<u16, ABError> = Ok::<u8, AError>(1).strict().loose_into();
+	/// assert_eq!(x, Ok(1));
+	/// let x: Result<u16, ABError> = Err::<u8, AError>(AError).strict().loose_into();
+	/// assert_eq!(x, Err(ABError::A(AError)));
+	/// ```
+	pub fn loose_into<A2: From<A>, B2: From<B>>(self) -> Result<A2, B2> {
+		self.map_both(A2::from, B2::from).loose()
+	}
+
+	/// Converts the error into a `String` with its `Display` implementation, and converts into a
+	/// `Result`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let x = "x".parse::<i32>().strict().err_to_string();
+	/// assert_eq!(x, Err("invalid digit found in string".to_string()));
+	/// assert_eq!("1".parse::<i32>().strict().err_to_string(), Ok(1));
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn err_to_string(self) -> Result<A, alloc::string::String> where B: core::fmt::Display {
+		use alloc::string::ToString;
+		self.0.map_err(|e| e.to_string())
+	}
+
+	/// Boxes the error as a `dyn Error`, and converts into a `Result`.
+	///
+	/// This is the same conversion that `?` would do into a `Box<dyn Error + Send + Sync>`, but
+	/// spelled out explicitly.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let x = "12".parse::<i32>().strict().into_boxed();
+	/// assert_eq!(x.unwrap(), 12);
+	///
+	/// let x = "x".parse::<i32>().strict().into_boxed();
+	/// let err = x.unwrap_err();
+	/// assert!(err.is::<std::num::ParseIntError>());
+	/// assert_eq!(err.to_string(), "invalid digit found in string");
+	/// ```
+	#[cfg(feature = "alloc")]
+	pub fn into_boxed(self) -> Result<A, alloc::boxed::Box<dyn core::error::Error + Send + Sync>>
+	where
+		B: core::error::Error + Send + Sync + 'static,
+	{
+		self.0.map_err(|e| alloc::boxed::Box::new(e) as _)
+	}
+
+	/// Maps the `Err` value with `f`, with exactly the closure's output as the new error type.
+	///
+	/// This is the same as [`map_err`](StrictResult::map_err), but spelled out for use in generic
+	/// code where the error type must be pinned. Since the result stays strict, a following `?`
+	/// requires the mapped error to be exactly the error type of the function, which resolves
+	/// conversions like `Into::into` that would otherwise be ambiguous:
+	///
+	/// ```compile_fail
+	/// struct AError;
+	/// struct BError;
+	///
+	/// fn lenient<E: From<AError> + From<BError>>(r: Result<(), AError>) -> Result<(), E> {
+	///     r.map_err(Into::into)?; // type annotations needed
+	///     Ok(())
+	/// }
+	/// ```
+	///
+	#[cfg_attr(feature = "stable", doc = "```ignore")]
+	#[cfg_attr(not(feature = "stable"), doc = "```")]
+	/// use strict_result::Strict;
+	///
+	/// struct AError;
+	/// struct BError;
+	///
+	/// fn strict<E: From<AError> + From<BError>>(r: Result<(), AError>) -> Result<(), E> {
+	///     r.strict().strict_map_err(Into::into)?;
+	///     Ok(())
+	/// }
+	/// ```
+	pub fn strict_map_err<F>(self, f: impl FnOnce(B) -> F) -> StrictResult<A, F> {
+		self.map_err(f)
+	}
+
+	/// Combines two results into a result of a tuple, returning the first error encountered.
+	///
+	/// Both results must have the same error type.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let ok = |v: i32| Ok::<i32, &str>(v).strict();
+	/// let err = |e: &'static str| Err::<i32, &str>(e).strict();
+	/// assert_eq!(ok(1).zip(ok(2)), Ok((1, 2)));
+	/// assert_eq!(err("first").zip(ok(2)), Err("first"));
+	/// assert_eq!(ok(1).zip(err("second")), Err("second"));
+	/// assert_eq!(err("first").zip(err("second")), Err("first"));
+	/// ```
+	pub fn zip<C>(self, other: StrictResult<C, B>) -> StrictResult<(A, C), B> {
+		self.and_then(|a| other.map(|c| (a, c)))
+	}
+
+	/// Converts the error type with `From`.
+	///
+	/// This is the same as [`widen`](StrictResult::widen).
+	///
+	/// ```
+	/// use std::error::Error;
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// let x: StrictResult<i32, Box<dyn Error>> = "x".parse::<i32>().strict().err_into();
+	/// assert_eq!(x.unwrap_err().to_string(), "invalid digit found in string");
+	/// ```
+	pub fn err_into<F: From<B>>(self) -> StrictResult<A, F> {
+		self.widen()
+	}
+
+	/// Converts the success type with `From`.
+	///
+	/// ```
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// #[derive(Debug, PartialEq)]
+	/// struct Meters(f64);
+	///
+	/// impl From<f64> for Meters {
+	///     fn from(v: f64) -> Meters { Meters(v) }
+	/// }
+	///
+	/// let x: StrictResult<Meters, ()> = Ok(2.5).strict().ok_into();
+	/// assert_eq!(x, Ok(Meters(2.5)));
+	/// ```
+	pub fn ok_into<U: From<A>>(self) -> StrictResult<U, B> {
+		self.map(U::from)
+	}
+
+	/// Converts into a `ControlFlow`, which continues with the `Ok` value or breaks with the error.
+	///
+	/// This is the same as what `?` does.
+	///
+	/// ```
+	/// use std::ops::ControlFlow;
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// assert_eq!(Ok::<i32, &str>(1).strict().into_control_flow(), ControlFlow::Continue(1));
+	/// assert_eq!(Err::<i32, &str>("bad").strict().into_control_flow(), ControlFlow::Break(Err("bad").strict()));
+	///
+	/// for x in [Ok(1).strict(), Err("bad").strict()] {
+	///     assert_eq!(StrictResult::from_control_flow(x.into_control_flow()), x);
+	/// }
+	/// ```
+	pub fn into_control_flow(self) -> ControlFlow<StrictResult<Infallible, B>, A> {
+		match self.0 {
+			Ok(v) => ControlFlow::Continue(v),
+			Err(e) => ControlFlow::Break(Err(e).strict()),
+		}
+	}
+
+	/// Converts from a `ControlFlow`, as returned by [`into_control_flow`](StrictResult::into_control_flow).
+	///
+	/// ```
+	/// use std::ops::ControlFlow;
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// assert_eq!(StrictResult::<i32, &str>::from_control_flow(ControlFlow::Continue(1)), Ok(1));
+	/// assert_eq!(StrictResult::<i32, &str>::from_control_flow(ControlFlow::Break(Err("bad").strict())), Err("bad"));
+	/// ```
+	pub fn from_control_flow(c: ControlFlow<StrictResult<Infallible, B>, A>) -> Self {
+		match c {
+			ControlFlow::Continue(v) => Ok(v).strict(),
+			ControlFlow::Break(r) => Err(r.into_err()).strict(),
+		}
+	}
+
+	/// Converts into a `ControlFlow` like [`into_control_flow`](StrictResult::into_control_flow),
+	/// but with the error as a plain `Result` residual.
+	///
+	/// This is the same residual that `?` on a `Result` produces, which is convenient when building
+	/// custom `?`-like operators.
+	///
+	/// ```
+	/// use std::convert::Infallible;
+	/// use std::ops::ControlFlow;
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, &str>(1).strict().branch_loose(), ControlFlow::Continue(1));
+	/// assert_eq!(Err::<i32, &str>("bad").strict().branch_loose(), ControlFlow::Break(Err::<Infallible, _>("bad")));
+	/// ```
+	pub fn branch_loose(self) -> ControlFlow<Result<Infallible, B>, A> {
+		match self.0 {
+			Ok(v) => ControlFlow::Continue(v),
+			Err(e) => ControlFlow::Break(Err(e)),
+		}
+	}
+
+	/// Converts into a ready `Poll` of the inner `Result`, for use in `poll` functions.
+	///
+	/// ```
+	/// use std::task::Poll;
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// assert_eq!(Ok::<i32, &str>(1).strict().into_poll(), Poll::Ready(Ok(1)));
+	/// for x in [Ok(1).strict(), Err("bad").strict()] {
+	///     assert_eq!(StrictResult::from_poll_ready(x.into_poll()), Some(x));
+	/// }
+	/// ```
+	pub fn into_poll(self) -> core::task::Poll<Result<A, B>> {
+		core::task::Poll::Ready(self.0)
+	}
+
+	/// Converts from a `Poll` of a `Result`, returning `None` if it is `Pending`.
+	///
+	/// ```
+	/// use std::task::Poll;
+	/// use strict_result::StrictResult;
+	///
+	/// assert_eq!(StrictResult::from_poll_ready(Poll::Ready(Err::<i32, _>("bad"))), Some(Err("bad").into()));
+	/// assert_eq!(StrictResult::<i32, &str>::from_poll_ready(Poll::Pending), None);
+	/// ```
+	pub fn from_poll_ready(poll: core::task::Poll<Result<A, B>>) -> Option<Self> {
+		match poll {
+			core::task::Poll::Ready(r) => Some(r.strict()),
+			core::task::Poll::Pending => None,
+		}
+	}
+
+	/// Drops the result, explicitly ignoring any error.
+	///
+	/// This is an alternative to `let _ = ...` for silencing the `unused_must_use` lint.
+	///
+	/// ```compile_fail
+	/// #![deny(unused_must_use)]
+	/// use strict_result::Strict;
+	///
+	/// Err::<(), &str>("ignored").strict();
+	/// ```
+	///
+	/// ```
+	/// #![deny(unused_must_use)]
+	/// use strict_result::Strict;
+	///
+	/// Err::<(), &str>("ignored").strict().discard();
+	/// ```
+	pub fn discard(self) {}
 }
 
+impl<A, B> StrictResult<Option<A>, B> {
+	/// Transposes a `StrictResult` of an `Option` into an `Option` of a `StrictResult`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<Option<i32>, ()>(None).strict().transpose(), None);
+	/// assert_eq!(Ok::<Option<i32>, ()>(Some(1)).strict().transpose(), Some(Ok(1).strict()));
+	/// assert_eq!(Err::<Option<i32>, ()>(()).strict().transpose(), Some(Err(()).strict()));
+	/// ```
+	pub fn transpose(self) -> Option<StrictResult<A, B>> {
+		self.0.transpose().map(Strict::strict)
+	}
+}
+
+impl<A, B> StrictResult<StrictResult<A, B>, B> {
+	/// Removes one level of nesting.
+	///
+	/// Both levels must have the same error type; no conversion takes place.
+	///
+	/// ```
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// let x: StrictResult<StrictResult<i32, &str>, &str> = Ok(Ok(1).strict()).strict();
+	/// assert_eq!(x.flatten(), Ok(1));
+	/// let x: StrictResult<StrictResult<i32, &str>, &str> = Ok(Err("inner").strict()).strict();
+	/// assert_eq!(x.flatten(), Err("inner"));
+	/// let x: StrictResult<StrictResult<i32, &str>, &str> = Err("outer").strict();
+	/// assert_eq!(x.flatten(), Err("outer"));
+	/// ```
+	pub fn flatten(self) -> StrictResult<A, B> {
+		self.and_then(|v| v)
+	}
+
+	/// Removes one level of nesting, and converts into a `Result`.
+	///
+	/// ```
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// let x: StrictResult<StrictResult<i32, &str>, &str> = Ok(Ok(1).strict()).strict();
+	/// assert_eq!(x.flatten_loose(), Ok::<i32, &str>(1));
+	/// let x: StrictResult<StrictResult<i32, &str>, &str> = Ok(Err("inner").strict()).strict();
+	/// assert_eq!(x.flatten_loose(), Err::<i32, &str>("inner"));
+	/// let x: StrictResult<StrictResult<i32, &str>, &str> = Err("outer").strict();
+	/// assert_eq!(x.flatten_loose(), Err::<i32, &str>("outer"));
+	/// ```
+	pub fn flatten_loose(self) -> Result<A, B> {
+		self.flatten().loose()
+	}
+}
+
+impl<A, C, B: Clone> StrictResult<(A, C), B> {
+	/// Splits a result of a tuple into a tuple of results.
+	///
+	/// If it is an `Err`, both halves get a clone of the error.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<(i32, char), &str>((1, 'a')).strict().unzip(), (Ok(1).strict(), Ok('a').strict()));
+	/// assert_eq!(Err::<(i32, char), &str>("bad").strict().unzip(), (Err("bad").strict(), Err("bad").strict()));
+	/// ```
+	pub fn unzip(self) -> (StrictResult<A, B>, StrictResult<C, B>) {
+		match self.0 {
+			Ok((a, c)) => (Ok(a).strict(), Ok(c).strict()),
+			Err(e) => (Err(e.clone()).strict(), Err(e).strict()),
+		}
+	}
+}
+
+impl<A> StrictResult<A, Infallible> {
+	/// Returns the `Ok` value, which is always present since the error type is uninhabited.
+	///
+	/// ```
+	/// use std::convert::Infallible;
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, Infallible>(1).strict().into_ok(), 1);
+	/// ```
+	pub fn into_ok(self) -> A {
+		match self.0 {
+			Ok(v) => v,
+			Err(e) => match e {},
+		}
+	}
+
+	/// Converts into a `StrictResult` with any error type, since there can never be an error.
+	///
+	/// ```
+	/// use std::convert::Infallible;
+	/// use strict_result::{Strict, StrictResult};
+	///
+	/// let x: StrictResult<i32, Infallible> = Ok(1).strict();
+	/// let y: StrictResult<i32, String> = x.absorb();
+	/// assert_eq!(y, Ok(1));
+	/// ```
+	pub fn absorb<B2>(self) -> StrictResult<A, B2> {
+		Ok(self.into_ok()).strict()
+	}
+}
+
+impl<B> StrictResult<Infallible, B> {
+	/// Returns the `Err` value, which is always present since the success type is uninhabited.
+	///
+	/// ```
+	/// use std::convert::Infallible;
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Err::<Infallible, i32>(1).strict().into_err(), 1);
+	/// ```
+	pub fn into_err(self) -> B {
+		match self.0 {
+			Ok(v) => match v {},
+			Err(e) => e,
+		}
+	}
+}
+
+impl<A, B> StrictResult<&A, B> {
+	/// Maps a `StrictResult<&A, B>` to a `StrictResult<A, B>` by copying the `Ok` value.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let x = Ok::<i32, ()>(1).strict();
+	/// assert_eq!(x.as_ref().map_err(|_| ()).copied(), x);
+	/// ```
+	pub fn copied(self) -> StrictResult<A, B> where A: Copy {
+		self.0.copied().strict()
+	}
+
+	/// Maps a `StrictResult<&A, B>` to a `StrictResult<A, B>` by cloning the `Ok` value.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let x = Ok::<String, ()>("a".into()).strict();
+	/// assert_eq!(x.as_ref().map_err(|_| ()).cloned(), x);
+	/// ```
+	pub fn cloned(self) -> StrictResult<A, B> where A: Clone {
+		self.0.cloned().strict()
+	}
+}
+
+impl<A, B> StrictResult<&mut A, B> {
+	/// Maps a `StrictResult<&mut A, B>` to a `StrictResult<A, B>` by copying the `Ok` value.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut x = Ok::<i32, ()>(1).strict();
+	/// assert_eq!(x.as_mut().map_err(|_| ()).copied(), Ok(1));
+	/// ```
+	pub fn copied(self) -> StrictResult<A, B> where A: Copy {
+		self.0.copied().strict()
+	}
+
+	/// Maps a `StrictResult<&mut A, B>` to a `StrictResult<A, B>` by cloning the `Ok` value.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// let mut x = Ok::<String, ()>("a".into()).strict();
+	/// assert_eq!(x.as_mut().map_err(|_| ()).cloned(), Ok("a".into()));
+	/// ```
+	pub fn cloned(self) -> StrictResult<A, B> where A: Clone {
+		self.0.cloned().strict()
+	}
+}
+
+#[cfg(not(feature = "stable"))]
 impl<A, B> FromResidual<StrictResult<Infallible, B>> for StrictResult<A, B> {
 	fn from_residual(r: StrictResult<Infallible, B>) -> Self {
 		match r.loose() {
@@ -112,18 +2096,63 @@ impl<A, B> FromResidual<StrictResult<Infallible, B>> for StrictResult<A, B> {
 	}
 }
 
+#[cfg(not(feature = "stable"))]
 impl<A, B> FromResidual<StrictResult<Infallible, B>> for Result<A, B> {
 	fn from_residual(r: StrictResult<Infallible, B>) -> Self {
 		StrictResult::from_residual(r).loose()
 	}
 }
 
+/// Allows `?` on a `Result` in a function returning `StrictResult`.
+///
+/// Like in a function returning `Result`, this converts the error with `From`. This means that a
+/// closure returning `StrictResult` can still fail to infer its error type if nothing else pins it
+/// down:
+///
+/// ```compile_fail
+/// use strict_result::Strict;
+///
+/// fn passthrough<T>(f: impl FnOnce() -> T) -> T {
+///     f()
+/// }
+///
+/// let r = passthrough(|| {
+///     "1".parse::<i32>()?;
+///     Ok(()).strict() // type annotations needed
+/// });
+/// assert!(r.is_ok());
+/// ```
+///
+/// Either making the inner `?` strict, or naming the error type, resolves this:
+///
+/// ```
+/// use std::num::ParseIntError;
+/// use strict_result::{Strict, StrictResult};
+///
+/// fn passthrough<T>(f: impl FnOnce() -> T) -> T {
+///     f()
+/// }
+///
+/// let r = passthrough(|| {
+///     "1".parse::<i32>().strict()?;
+///     Ok(()).strict()
+/// });
+/// assert!(r.is_ok());
+///
+/// let r = passthrough(|| -> StrictResult<_, ParseIntError> {
+///     "x".parse::<i32>()?;
+///     Ok(()).strict()
+/// });
+/// assert!(r.is_err());
+/// ```
+#[cfg(not(feature = "stable"))]
 impl<A, B, B2: From<B>> FromResidual<Result<Infallible, B>> for StrictResult<A, B2> {
 	fn from_residual(r: Result<Infallible, B>) -> Self {
 		Result::from_residual(r).strict()
 	}
 }
 
+#[cfg(not(feature = "stable"))]
 impl<A, B> Try for StrictResult<A, B> {
 	type Output = A;
 	type Residual = StrictResult<Infallible, B>;
@@ -133,9 +2162,189 @@ impl<A, B> Try for StrictResult<A, B> {
 	}
 
 	fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
-		match self.loose() {
-			Ok(v) => ControlFlow::Continue(v),
-			Err(e) => ControlFlow::Break(Err(e).strict()),
+		self.into_control_flow()
+	}
+}
+
+/// Allows `try` blocks to evaluate to a `StrictResult`.
+///
+/// A `try` block has the same type as the first `?` in it, so using `.strict()?` gives a
+/// `StrictResult`. This requires the `try_blocks` feature.
+///
+/// ```
+/// #![feature(try_blocks)]
+/// use strict_result::{Strict, StrictResult};
+///
+/// fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+///     s.parse()
+/// }
+///
+/// let x: StrictResult<i32, _> = try { parse("1").strict()? + parse("2").strict()? };
+/// assert_eq!(x, Ok(3));
+/// let x: StrictResult<i32, _> = try { parse("1").strict()? + parse("x").strict()? };
+/// assert!(x.is_err());
+/// ```
+#[cfg(not(feature = "stable"))]
+impl<A, B> Residual<A> for StrictResult<Infallible, B> {
+	type TryType = StrictResult<A, B>;
+}
+
+/// Allows `do yeet e` in functions returning a `StrictResult`, returning `Err(e)` without
+/// conversion.
+///
+/// ```
+/// #![feature(yeet_expr)]
+/// use strict_result::{Strict, StrictResult};
+///
+/// fn check(v: i32) -> StrictResult<i32, &'static str> {
+///     if v < 0 {
+///         do yeet "negative";
+///     }
+///     Ok(v).strict()
+/// }
+///
+/// assert_eq!(check(1), Ok(1));
+/// assert_eq!(check(-1), Err("negative"));
+/// ```
+#[cfg(all(feature = "yeet", not(feature = "stable")))]
+impl<A, B> FromResidual<core::ops::Yeet<B>> for StrictResult<A, B> {
+	fn from_residual(core::ops::Yeet(e): core::ops::Yeet<B>) -> Self {
+		Err(e).strict()
+	}
+}
+
+/// The residual of using `?` on a `None` in a function returning a `StrictResult`.
+///
+/// Error types opt into this by implementing `From<NoneError>`, whose result is then returned as
+/// the `Err` value. This is the only conversion performed; there is no general `.into()` on
+/// `Option` residuals.
+///
+/// ```
+/// use strict_result::{NoneError, Strict, StrictResult};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum LookupError {
+///     Missing,
+/// }
+///
+/// impl From<NoneError> for LookupError {
+///     fn from(_: NoneError) -> Self { LookupError::Missing }
+/// }
+///
+/// fn first_even(v: &[i32]) -> StrictResult<i32, LookupError> {
+///     let x = v.iter().find(|x| *x % 2 == 0)?;
+///     Ok(*x).strict()
+/// }
+///
+/// assert_eq!(first_even(&[1, 2, 3]), Ok(2));
+/// assert_eq!(first_even(&[1, 3]), Err(LookupError::Missing));
+/// ```
+#[cfg(not(feature = "stable"))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NoneError;
+
+#[cfg(not(feature = "stable"))]
+impl<A, B: From<NoneError>> FromResidual<Option<Infallible>> for StrictResult<A, B> {
+	fn from_residual(_: Option<Infallible>) -> Self {
+		Err(NoneError.into()).strict()
+	}
+}
+
+/// Allows returning a `StrictResult` from `main`, behaving like the inner `Result`.
+#[cfg(feature = "std")]
+impl<A: std::process::Termination, B: core::fmt::Debug> std::process::Termination for StrictResult<A, B> {
+	fn report(self) -> std::process::ExitCode {
+		self.0.report()
+	}
+}
+
+#[doc(hidden)]
+pub mod __private {
+	use crate::{StrictOption, StrictResult};
+
+	/// Used by the `#[strict]` and `strict_block!` macros to make `?` strict, without touching values
+	/// that already are.
+	pub trait IntoStrict {
+		type Strict;
+		fn into_strict(self) -> Self::Strict;
+	}
+
+	impl<A, B> IntoStrict for Result<A, B> {
+		type Strict = StrictResult<A, B>;
+		fn into_strict(self) -> StrictResult<A, B> {
+			StrictResult(self)
+		}
+	}
+
+	impl<A, B> IntoStrict for StrictResult<A, B> {
+		type Strict = StrictResult<A, B>;
+		fn into_strict(self) -> StrictResult<A, B> {
+			self
+		}
+	}
+
+	// The only conversion `?` performs on `Option` is the opt-in one through `NoneError`, so it is
+	// left as is.
+	impl<A> IntoStrict for Option<A> {
+		type Strict = Option<A>;
+		fn into_strict(self) -> Option<A> {
+			self
+		}
+	}
+
+	impl<A> IntoStrict for StrictOption<A> {
+		type Strict = StrictOption<A>;
+		fn into_strict(self) -> StrictOption<A> {
+			self
+		}
+	}
+
+	// `?` on a `Widen` is explicitly lenient, so it is left as is.
+	#[cfg(not(feature = "stable"))]
+	impl<A, B> IntoStrict for crate::Widen<A, B> {
+		type Strict = crate::Widen<A, B>;
+		fn into_strict(self) -> crate::Widen<A, B> {
+			self
+		}
+	}
+
+	// `?` on these is allowed in functions returning other types, so they can't be made strict
+	// without changing what they return into. They are left as is.
+	impl<B, C> IntoStrict for core::ops::ControlFlow<B, C> {
+		type Strict = core::ops::ControlFlow<B, C>;
+		fn into_strict(self) -> core::ops::ControlFlow<B, C> {
+			self
+		}
+	}
+
+	impl<A, B> IntoStrict for core::task::Poll<Result<A, B>> {
+		type Strict = core::task::Poll<Result<A, B>>;
+		fn into_strict(self) -> core::task::Poll<Result<A, B>> {
+			self
+		}
+	}
+
+	impl<A, B> IntoStrict for core::task::Poll<Option<Result<A, B>>> {
+		type Strict = core::task::Poll<Option<Result<A, B>>>;
+		fn into_strict(self) -> core::task::Poll<Option<Result<A, B>>> {
+			self
+		}
+	}
+
+	/// Used by the `strict_try!` macro to return an error without converting it.
+	pub trait FromStrictErr<B> {
+		fn from_strict_err(e: B) -> Self;
+	}
+
+	impl<A, B> FromStrictErr<B> for Result<A, B> {
+		fn from_strict_err(e: B) -> Self {
+			Err(e)
+		}
+	}
+
+	impl<A, B> FromStrictErr<B> for StrictResult<A, B> {
+		fn from_strict_err(e: B) -> Self {
+			StrictResult(Err(e))
 		}
 	}
 }
@@ -0,0 +1,150 @@
+#[cfg(not(feature = "stable"))]
+use core::convert::Infallible;
+#[cfg(not(feature = "stable"))]
+use core::ops::{ControlFlow, Try, FromResidual, Residual};
+
+use crate::seal;
+
+/// A wrapper around `Option` that is strict about the `?` operator, like [`StrictResult`](crate::StrictResult).
+///
+/// It can be converted to and from `Option` with the [`strict`](StrictOptionExt::strict) and
+/// [`loose`](StrictOption::loose) functions.
+///
+/// `?` on a `StrictOption` only returns into an `Option` or another `StrictOption`. Unlike `?` on a
+/// bare `Option`, it never turns `None` into an error through `From<NoneError>`, so a missing value
+/// cannot silently become an `Err` of whatever type the function happens to return.
+///
+/// ```compile_fail
+/// use strict_result::{NoneError, Strict, StrictOptionExt, StrictResult};
+///
+/// struct LookupError;
+/// impl From<NoneError> for LookupError {
+///     fn from(_: NoneError) -> Self { LookupError }
+/// }
+///
+/// fn first(v: &[i32]) -> StrictResult<i32, LookupError> {
+///     let x = v.first().strict()?; // `StrictOption` is not converted
+///     Ok(*x).strict()
+/// }
+/// ```
+///
+/// The `None` case has to be handled explicitly instead, or returned into an `Option`.
+///
+#[cfg_attr(feature = "stable", doc = "```ignore")]
+#[cfg_attr(not(feature = "stable"), doc = "```")]
+/// use strict_result::{Strict, StrictOptionExt, StrictResult};
+///
+/// struct LookupError;
+///
+/// fn first(v: &[i32]) -> StrictResult<i32, LookupError> {
+///     let x = v.first().ok_or(LookupError).strict()?;
+///     Ok(*x).strict()
+/// }
+///
+/// fn second(v: &[i32]) -> Option<i32> {
+///     v.first().strict()?;
+///     v.get(1).copied()
+/// }
+///
+/// assert!(first(&[]).is_err());
+/// assert_eq!(second(&[1, 2]), Some(2));
+/// assert_eq!(second(&[1]), None);
+/// ```
+#[repr(transparent)]
+#[must_use = "the contained `Option` may be a `None` variant, which should be handled"]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StrictOption<A>(pub(crate) Option<A>);
+
+/// Formats the inner `Option`, followed by `.strict()` to distinguish it from a bare `Option`.
+///
+/// ```
+/// use strict_result::StrictOptionExt;
+///
+/// assert_eq!(format!("{:?}", Some(1).strict()), "Some(1).strict()");
+/// assert_eq!(format!("{:?}", None::<i32>.strict()), "None.strict()");
+/// ```
+impl<A: core::fmt::Debug> core::fmt::Debug for StrictOption<A> {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		self.0.fmt(f)?;
+		write!(f, ".strict()")
+	}
+}
+
+/// Provides the `.strict()?` function for `Option`.
+///
+/// See [`StrictOption`] for details.
+pub trait StrictOptionExt<A>: seal::Sealed {
+	/// Converts an `Option` into a `StrictOption`.
+	fn strict(self) -> StrictOption<A>;
+}
+
+impl<A> StrictOptionExt<A> for Option<A> {
+	fn strict(self) -> StrictOption<A> {
+		StrictOption(self)
+	}
+}
+
+impl<A> StrictOption<A> {
+	/// Converts a `StrictOption` into an `Option`.
+	pub fn loose(self) -> Option<A> {
+		self.0
+	}
+}
+
+#[cfg(not(feature = "stable"))]
+impl<A> FromResidual<StrictOption<Infallible>> for StrictOption<A> {
+	fn from_residual(r: StrictOption<Infallible>) -> Self {
+		match r.loose() {
+			Some(v) => match v {},
+			None => None
+		}.strict()
+	}
+}
+
+#[cfg(not(feature = "stable"))]
+impl<A> FromResidual<StrictOption<Infallible>> for Option<A> {
+	fn from_residual(r: StrictOption<Infallible>) -> Self {
+		StrictOption::from_residual(r).loose()
+	}
+}
+
+#[cfg(not(feature = "stable"))]
+impl<A> FromResidual<Option<Infallible>> for StrictOption<A> {
+	fn from_residual(r: Option<Infallible>) -> Self {
+		Option::from_residual(r).strict()
+	}
+}
+
+#[cfg(not(feature = "stable"))]
+impl<A> Try for StrictOption<A> {
+	type Output = A;
+	type Residual = StrictOption<Infallible>;
+
+	fn from_output(r: A) -> Self {
+		Some(r).strict()
+	}
+
+	fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+		match self.loose() {
+			Some(v) => ControlFlow::Continue(v),
+			None => ControlFlow::Break(None.strict()),
+		}
+	}
+}
+
+/// Allows `try` blocks to evaluate to a `StrictOption`.
+///
+/// ```
+/// #![feature(try_blocks)]
+/// use strict_result::{StrictOption, StrictOptionExt};
+///
+/// let v = [1, 2];
+/// let x: StrictOption<i32> = try { v.first().strict()? + v.get(1).strict()? };
+/// assert_eq!(x.loose(), Some(3));
+/// let x: StrictOption<i32> = try { v.first().strict()? + v.get(2).strict()? };
+/// assert_eq!(x.loose(), None);
+/// ```
+#[cfg(not(feature = "stable"))]
+impl<A> Residual<A> for StrictOption<Infallible> {
+	type TryType = StrictOption<A>;
+}
@@ -0,0 +1,29 @@
+use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use crate::{Strict, StrictResult};
+
+/// Collects the `Ok` values in parallel, stopping early if any `Err` is found.
+///
+/// The error is carried through as is, without any conversion.
+///
+/// ```
+/// use rayon::prelude::*;
+/// use strict_result::{Strict, StrictResult};
+///
+/// let check = |i: i32| if i == 500 { Err(i) } else { Ok(i) }.strict();
+///
+/// let v: StrictResult<Vec<i32>, i32> = (0..500).into_par_iter().map(check).collect();
+/// assert_eq!(v, Ok((0..500).collect()));
+/// let v: StrictResult<Vec<i32>, i32> = (0..1000).into_par_iter().map(check).collect();
+/// assert_eq!(v, Err(500));
+/// ```
+impl<A, B, C> FromParallelIterator<StrictResult<A, B>> for StrictResult<C, B>
+where
+	A: Send,
+	B: Send,
+	C: FromParallelIterator<A>,
+{
+	fn from_par_iter<I: IntoParallelIterator<Item = StrictResult<A, B>>>(par_iter: I) -> Self {
+		par_iter.into_par_iter().map(StrictResult::loose).collect::<Result<C, B>>().strict()
+	}
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Strict, StrictOption, StrictOptionExt, StrictResult};
+
+/// Serializes identically to the inner `Result`.
+///
+/// ```
+/// use strict_result::{Strict, StrictResult};
+///
+/// let ok = Ok::<i32, String>(1).strict();
+/// let json = serde_json::to_string(&ok).unwrap();
+/// assert_eq!(json, r#"{"Ok":1}"#);
+/// assert_eq!(serde_json::from_str::<StrictResult<i32, String>>(&json).unwrap(), ok);
+///
+/// let err = Err::<i32, String>("bad".into()).strict();
+/// let json = serde_json::to_string(&err).unwrap();
+/// assert_eq!(json, r#"{"Err":"bad"}"#);
+/// assert_eq!(serde_json::from_str::<StrictResult<i32, String>>(&json).unwrap(), err);
+/// ```
+impl<A: Serialize, B: Serialize> Serialize for StrictResult<A, B> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.0.serialize(serializer)
+	}
+}
+
+impl<'de, A: Deserialize<'de>, B: Deserialize<'de>> Deserialize<'de> for StrictResult<A, B> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Result::deserialize(deserializer).map(Strict::strict)
+	}
+}
+
+/// Serializes identically to the inner `Option`.
+///
+/// ```
+/// use strict_result::{StrictOption, StrictOptionExt};
+///
+/// let some = Some(1).strict();
+/// let json = serde_json::to_string(&some).unwrap();
+/// assert_eq!(json, "1");
+/// assert_eq!(serde_json::from_str::<StrictOption<i32>>(&json).unwrap(), some);
+///
+/// let none = None::<i32>.strict();
+/// let json = serde_json::to_string(&none).unwrap();
+/// assert_eq!(json, "null");
+/// assert_eq!(serde_json::from_str::<StrictOption<i32>>(&json).unwrap(), none);
+/// ```
+impl<A: Serialize> Serialize for StrictOption<A> {
+	fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		self.0.serialize(serializer)
+	}
+}
+
+impl<'de, A: Deserialize<'de>> Deserialize<'de> for StrictOption<A> {
+	fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		Option::deserialize(deserializer).map(StrictOptionExt::strict)
+	}
+}
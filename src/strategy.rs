@@ -0,0 +1,32 @@
+use proptest::strategy::Strategy;
+
+use crate::{Strict, StrictResult};
+
+/// Creates a `proptest` strategy for `StrictResult`s, with values taken from `ok` and `err`.
+///
+/// `probability_of_ok` is the probability, between 0.0 and 1.0 exclusive, that an `Ok` is chosen.
+/// Like [`proptest::result::maybe_ok_weighted`], generated values shrink towards `Err`.
+///
+/// ```
+/// use proptest::prelude::*;
+/// use proptest::strategy::ValueTree;
+/// use proptest::test_runner::TestRunner;
+/// use strict_result::{strict_result_strategy, Strict};
+///
+/// let strategy = strict_result_strategy(any::<i32>(), "[a-z]+", 0.9);
+/// TestRunner::default().run(&strategy, |x| {
+///     prop_assert_eq!(x.clone().loose().strict(), x);
+///     Ok(())
+/// }).unwrap();
+///
+/// let mut runner = TestRunner::deterministic();
+/// let oks = (0..1000).filter(|_| strategy.new_tree(&mut runner).unwrap().current().is_ok()).count();
+/// assert!(oks > 800);
+/// ```
+pub fn strict_result_strategy<SA: Strategy, SB: Strategy>(
+	ok: SA,
+	err: SB,
+	probability_of_ok: f64,
+) -> impl Strategy<Value = StrictResult<SA::Value, SB::Value>> {
+	proptest::result::maybe_ok_weighted(probability_of_ok, ok, err).prop_map(Strict::strict)
+}
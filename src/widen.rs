@@ -0,0 +1,83 @@
+use core::convert::Infallible;
+use core::ops::{ControlFlow, Try, FromResidual};
+
+use crate::{Strict, StrictResult};
+
+/// A wrapper around [`StrictResult`] whose `?` *does* convert the error with `From`.
+///
+/// **This deliberately reintroduces the implicit `.into()` that `StrictResult` exists to avoid**,
+/// along with the type inference problems that come with it. It is meant for functions that mix
+/// strict and lenient code, where a single `?` should be allowed to widen the error without
+/// leaving strict mode everywhere else. Prefer [`widen`](StrictResult::widen), which names the
+/// target type, where possible.
+///
+/// Created by [`StrictResult::widening`].
+///
+/// ```
+/// use strict_result::{Strict, StrictResult};
+///
+/// # #[derive(Debug, PartialEq)]
+/// # struct AError;
+/// # #[derive(Debug, PartialEq)]
+/// # enum ABError { A(AError) }
+/// #
+/// # impl From<AError> for ABError {
+/// #     fn from(a: AError) -> ABError { ABError::A(a) }
+/// # }
+/// #
+/// fn lenient(r: StrictResult<i32, AError>) -> Result<i32, ABError> {
+///     Ok(r.widening()? + 1)
+/// }
+/// fn strict(r: StrictResult<i32, AError>) -> StrictResult<i32, ABError> {
+///     Ok(r.widening()? + 1).strict()
+/// }
+///
+/// assert_eq!(lenient(Ok(1).strict()), Ok(2));
+/// assert_eq!(lenient(Err(AError).strict()), Err(ABError::A(AError)));
+/// assert_eq!(strict(Err(AError).strict()), Err(ABError::A(AError)));
+/// ```
+#[repr(transparent)]
+#[must_use = "the contained `StrictResult` may be an `Err` variant, which should be handled"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Widen<A, B>(StrictResult<A, B>);
+
+impl<A, B> StrictResult<A, B> {
+	/// Wraps the result so that `?` converts the error with `From`.
+	///
+	/// See [`Widen`] for details.
+	pub fn widening(self) -> Widen<A, B> {
+		Widen(self)
+	}
+}
+
+impl<A, B> Widen<A, B> {
+	/// Unwraps the inner `StrictResult`.
+	///
+	/// ```
+	/// use strict_result::Strict;
+	///
+	/// assert_eq!(Ok::<i32, ()>(1).strict().widening().strict(), Ok(1));
+	/// ```
+	pub fn strict(self) -> StrictResult<A, B> {
+		self.0
+	}
+}
+
+impl<A, B, B2: From<B>> FromResidual<Result<Infallible, B>> for Widen<A, B2> {
+	fn from_residual(r: Result<Infallible, B>) -> Self {
+		Widen(Result::from_residual(r).strict())
+	}
+}
+
+impl<A, B> Try for Widen<A, B> {
+	type Output = A;
+	type Residual = Result<Infallible, B>;
+
+	fn from_output(a: A) -> Self {
+		Widen(Ok(a).strict())
+	}
+
+	fn branch(self) -> ControlFlow<Self::Residual, Self::Output> {
+		self.0.branch_loose()
+	}
+}
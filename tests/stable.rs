@@ -0,0 +1,35 @@
+#![cfg(feature = "stable")]
+
+use strict_result::{strict_try, Strict, StrictResult};
+
+fn passthrough<T>(f: impl FnOnce() -> T) -> T {
+	f()
+}
+
+fn parse(s: &str) -> Result<i32, std::num::ParseIntError> {
+	s.parse()
+}
+
+fn lenient(s: &str) -> Result<i32, std::num::ParseIntError> {
+	let v = strict_try!(passthrough(|| {
+		let v = strict_try!(parse(s));
+		Ok(v * 2)
+	}));
+	Ok(v + 1)
+}
+
+fn strict(s: &str) -> StrictResult<i32, std::num::ParseIntError> {
+	Ok(strict_try!(parse(s)) + 1).strict()
+}
+
+#[test]
+fn strict_try_in_result() {
+	assert_eq!(lenient("2"), Ok(5));
+	assert!(lenient("x").is_err());
+}
+
+#[test]
+fn strict_try_in_strict_result() {
+	assert_eq!(strict("2"), Ok(3));
+	assert!(strict("x").is_err());
+}